@@ -577,6 +577,18 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         self.solver().for_display(t)
     }
 
+    /// Clean up a type for showing to the user, e.g. on hover: force any remaining solver
+    /// variables, expand a top-level type alias to the value it stands for, and simplify
+    /// unions. Never reports errors.
+    #[cfg_attr(not(test), expect(dead_code))] // Hover currently reads answers, which are already forced
+    pub fn normalize_for_display(&self, ty: Type) -> Type {
+        let mut ty = self.solver().deep_force(ty);
+        if let Type::TypeAlias(ta) = &ty {
+            ty = self.solver().deep_force(ta.as_value(self.stdlib));
+        }
+        self.for_display(ty)
+    }
+
     pub fn get_from_module<K: Solve<Ans> + Keyed<EXPORTED = true>>(
         &self,
         module: ModuleName,
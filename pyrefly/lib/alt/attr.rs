@@ -9,6 +9,7 @@ use std::iter;
 
 use dupe::Dupe;
 use itertools::Either;
+use itertools::Itertools;
 use ruff_python_ast::Expr;
 use ruff_python_ast::name::Name;
 use ruff_text_size::TextRange;
@@ -26,9 +27,12 @@ use crate::error::context::ErrorContext;
 use crate::error::context::TypeCheckContext;
 use crate::error::context::TypeCheckKind;
 use crate::error::kind::ErrorKind;
+use crate::export::exports::ExportLocation;
 use crate::export::exports::Exports;
 use crate::module::module_info::TextRangeWithModuleInfo;
 use crate::module::module_name::ModuleName;
+use crate::module::module_path::ModulePath;
+use crate::types::callable::Callable;
 use crate::types::callable::FuncMetadata;
 use crate::types::callable::Function;
 use crate::types::callable::FunctionKind;
@@ -37,12 +41,18 @@ use crate::types::callable::Required;
 use crate::types::class::Class;
 use crate::types::class::ClassType;
 use crate::types::module::Module;
+use crate::types::qname::QName;
 use crate::types::quantified::Quantified;
 use crate::types::tuple::Tuple;
 use crate::types::type_var::Restriction;
 use crate::types::typed_dict::TypedDict;
 use crate::types::types::AnyStyle;
+use crate::types::types::BoundMethod;
+use crate::types::types::BoundMethodType;
+use crate::types::types::Forall;
+use crate::types::types::Forallable;
 use crate::types::types::Overload;
+use crate::types::types::OverloadType;
 use crate::types::types::SuperObj;
 use crate::types::types::Type;
 
@@ -340,7 +350,14 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 context,
                 todo_ctx,
             ) {
-                Ok(ty) => ty,
+                Ok(mut ty) => {
+                    // `Self` in the attribute type refers to the class it was declared on; when
+                    // accessed through a concrete instance, it is the type of that instance.
+                    if matches!(base, Type::ClassType(_)) {
+                        ty.subst_self_type_mut(base, &|a, b| self.is_subset_eq(a, b));
+                    }
+                    ty
+                }
                 Err(msg) => self.error(errors, range, ErrorKind::MissingAttribute, context, msg),
             }
         })
@@ -1226,7 +1243,9 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 signatures: _,
                 metadata: box metadata,
             }) => Some(AttributeBase::ClassInstance(
-                if let FunctionKind::CallbackProtocol(cls) = metadata.kind {
+                if let FunctionKind::CallbackProtocol(cls) | FunctionKind::Partial(cls) =
+                    metadata.kind
+                {
                     *cls
                 } else {
                     self.stdlib.function_type().clone()
@@ -1544,6 +1563,135 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
         res
     }
+
+    /// Render the signature of method `name` on instances of `cls` for display, e.g. on hover.
+    /// The `self` parameter is bound away and `Self` is resolved to the class. An overloaded
+    /// method produces one signature per line. Returns `None` if `name` isn't a method.
+    #[cfg_attr(not(test), expect(dead_code))] // Hover still renders the raw attribute type
+    pub fn method_signature_string(&self, cls: &Class, name: &Name) -> Option<String> {
+        let overload_signatures = |overload: Overload| {
+            overload
+                .signatures
+                .into_iter()
+                .map(|sig| match sig {
+                    OverloadType::Callable(callable) => callable,
+                    OverloadType::Forall(forall) => forall.body.signature,
+                })
+                .collect::<Vec<_>>()
+        };
+        let ty = self.type_of_attr_get(
+            &self.instantiate(cls),
+            name,
+            TextRange::default(),
+            &self.error_swallower(),
+            None,
+            "method_signature_string",
+        );
+        let signatures: Vec<Callable> = match ty {
+            Type::BoundMethod(box BoundMethod { func, .. }) => {
+                let signatures = match func {
+                    BoundMethodType::Function(func) => vec![func.signature],
+                    BoundMethodType::Forall(forall) => vec![forall.body.signature],
+                    BoundMethodType::Overload(overload) => overload_signatures(overload),
+                };
+                signatures
+                    .into_iter()
+                    .map(|sig| sig.drop_first_param().unwrap_or(sig))
+                    .collect()
+            }
+            // Static methods aren't bound, so they keep all their parameters.
+            Type::Function(box func)
+            | Type::Forall(box Forall {
+                body: Forallable::Function(func),
+                ..
+            }) => vec![func.signature],
+            Type::Overload(overload) => overload_signatures(overload),
+            _ => return None,
+        };
+        Some(
+            signatures
+                .into_iter()
+                .map(|sig| self.for_display(Type::Callable(Box::new(sig))))
+                .join("\n"),
+        )
+    }
+
+    /// Where the class, function, type alias or type variable that `ty` refers to is declared,
+    /// as the path of the declaring module and the range of the declaration in it. Functions
+    /// and type aliases only carry their name, so we find them either as methods of the object
+    /// they are bound to, or through the exports of the module they are defined in (functions)
+    /// or imported into (type aliases).
+    #[cfg_attr(not(test), expect(dead_code))] // For go-to-definition on a resolved type
+    pub fn definition_location(&self, ty: &Type) -> Option<(ModulePath, TextRange)> {
+        let from_qname = |qname: &QName| Some((qname.module_info().path().dupe(), qname.range()));
+        // Where the top-level `name` of `module` is defined, following re-exports.
+        let from_module = |mut module: ModuleName, name: &Name| {
+            let mut seen = SmallSet::new();
+            while seen.insert(module) {
+                let exports = self.exports.get(module).ok()?;
+                match exports.exports(self.exports).get(name)? {
+                    ExportLocation::ThisModule(export) => {
+                        return Some((exports.path().dupe(), export.location));
+                    }
+                    ExportLocation::OtherModule(other) => module = *other,
+                }
+            }
+            None
+        };
+        let from_function = |metadata: &FuncMetadata| {
+            let id = metadata.kind.as_func_id();
+            if id.cls.is_none() {
+                from_module(id.module, &id.func)
+            } else {
+                None
+            }
+        };
+        match ty {
+            Type::ClassDef(cls) => from_qname(cls.qname()),
+            Type::ClassType(cls) | Type::SelfType(cls) => from_qname(cls.qname()),
+            Type::TypedDict(typed_dict) => from_qname(typed_dict.qname()),
+            Type::Type(box inner) => self.definition_location(inner),
+            Type::TypeVar(tv) => from_qname(tv.qname()),
+            Type::ParamSpec(p) => from_qname(p.qname()),
+            Type::TypeVarTuple(tvt) => from_qname(tvt.qname()),
+            Type::TypeAlias(alias)
+            | Type::Forall(box Forall {
+                body: Forallable::TypeAlias(alias),
+                ..
+            }) => from_module(self.module_info().name(), &alias.name),
+            Type::Function(box func)
+            | Type::Forall(box Forall {
+                body: Forallable::Function(func),
+                ..
+            }) => from_function(&func.metadata),
+            Type::Overload(overload) => from_function(&overload.metadata),
+            Type::BoundMethod(box BoundMethod { obj, func }) => {
+                let metadata = match func {
+                    BoundMethodType::Function(func) => &func.metadata,
+                    BoundMethodType::Forall(forall) => &forall.body.metadata,
+                    BoundMethodType::Overload(overload) => &*overload.metadata,
+                };
+                let id = metadata.kind.as_func_id();
+                let cls = match obj {
+                    Type::ClassType(cls) | Type::SelfType(cls) => cls.class_object(),
+                    Type::ClassDef(cls) => cls,
+                    _ => return None,
+                };
+                iter::once(cls)
+                    .chain(
+                        self.get_metadata_for_class(cls)
+                            .ancestors(self.stdlib)
+                            .map(|x| x.class_object()),
+                    )
+                    .find(|c| id.cls.as_ref() == Some(c.name()))
+                    .and_then(|c| {
+                        let range = c.field_decl_range(&id.func)?;
+                        Some((c.module_info().path().dupe(), range))
+                    })
+            }
+            _ => None,
+        }
+    }
 }
 
 impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
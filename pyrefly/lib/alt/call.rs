@@ -8,6 +8,7 @@
 use dupe::Dupe;
 use ruff_python_ast::Keyword;
 use ruff_python_ast::name::Name;
+use ruff_text_size::Ranged;
 use ruff_text_size::TextRange;
 use starlark_map::small_set::SmallSet;
 use vec1::Vec1;
@@ -22,17 +23,23 @@ use crate::error::context::ErrorContext;
 use crate::error::kind::ErrorKind;
 use crate::types::callable::BoolKeywords;
 use crate::types::callable::Callable;
+use crate::types::callable::DataclassKeywords;
 use crate::types::callable::FuncFlags;
 use crate::types::callable::FuncMetadata;
 use crate::types::callable::Function;
 use crate::types::callable::FunctionKind;
+use crate::types::callable::Param;
+use crate::types::callable::ParamList;
 use crate::types::callable::Params;
+use crate::types::callable::Required;
 use crate::types::class::ClassType;
+use crate::types::class::TArgs;
 use crate::types::literal::Lit;
 use crate::types::type_var::Restriction;
 use crate::types::typed_dict::TypedDict;
 use crate::types::types::AnyStyle;
 use crate::types::types::BoundMethod;
+use crate::types::types::Overload;
 use crate::types::types::OverloadType;
 use crate::types::types::TParams;
 use crate::types::types::Type;
@@ -322,6 +329,36 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         )
     }
 
+    /// Calls a magic dunder method the way Python's operator machinery would, looking it up on the
+    /// type rather than the instance. Unlike [`Self::call_magic_dunder_method`], which silently
+    /// returns `None` so that callers can fall back to another protocol (e.g. `__iter__` to
+    /// `__getitem__`), a missing method is reported as an error and the call evaluates to the
+    /// error type, which is displayed as `Unknown`.
+    /// Unlike [`Self::call_method_or_error`], instance attributes and `__getattr__` are not consulted.
+    #[cfg_attr(not(test), expect(dead_code))] // Entry point for modelling custom operators outside the solver
+    pub fn call_dunder(
+        &self,
+        ty: &Type,
+        name: &Name,
+        args: &[CallArg],
+        range: TextRange,
+        errors: &ErrorCollector,
+    ) -> Type {
+        self.call_magic_dunder_method(ty, name, range, args, &[], errors, None)
+            .unwrap_or_else(|| {
+                self.error(
+                    errors,
+                    range,
+                    ErrorKind::MissingAttribute,
+                    None,
+                    format!(
+                        "Type `{}` has no magic method `{name}`",
+                        self.for_display(ty.clone()),
+                    ),
+                )
+            })
+    }
+
     /// If the metaclass defines a custom `__call__`, call it. If the `__call__` comes from `type`, ignore
     /// it because `type.__call__` behavior is baked into our constructor logic.
     fn call_metaclass(
@@ -446,6 +483,201 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
     }
 
+    /// `functools.partial(f, *args, **kwargs)` binds some of `f`'s arguments. We check the bound
+    /// arguments against the parameters of `f` and produce a callable taking the remaining ones.
+    /// If we can't see the parameters of `f`, or the arguments are unpacked, we fall back to
+    /// constructing a `partial` object as usual.
+    fn construct_partial(
+        &self,
+        cls: ClassType,
+        args: &[CallArg],
+        keywords: &[Keyword],
+        range: TextRange,
+        errors: &ErrorCollector,
+        context: Option<&dyn Fn() -> ErrorContext>,
+    ) -> Type {
+        let Some((CallArg::Expr(func), bound_args)) = args.split_first() else {
+            return self.construct_class(cls, args, keywords, range, errors, context);
+        };
+        let func_ty = self.expr_infer(func, errors);
+        let fallback = || {
+            let mut full_args = vec![CallArg::Type(&func_ty, func.range())];
+            full_args.extend_from_slice(bound_args);
+            self.construct_class(cls.clone(), &full_args, keywords, range, errors, context)
+        };
+        if bound_args
+            .iter()
+            .any(|arg| matches!(arg, CallArg::Star(..)))
+            || keywords.iter().any(|kw| kw.arg.is_none())
+        {
+            return fallback();
+        }
+        let Some(call_target) = self.as_call_target(func_ty.clone()) else {
+            return fallback();
+        };
+        let (self_arg, signatures, func_id) = match call_target.target {
+            Target::Callable(signature) => (None, Vec1::new(signature), None),
+            Target::Function(func) => (
+                None,
+                Vec1::new(func.signature),
+                Some(func.metadata.kind.as_func_id()),
+            ),
+            Target::BoundMethod(obj, func) => (
+                Some(obj),
+                Vec1::new(func.signature),
+                Some(func.metadata.kind.as_func_id()),
+            ),
+            Target::FunctionOverload(signatures, meta) => {
+                (None, signatures, Some(meta.kind.as_func_id()))
+            }
+            Target::BoundMethodOverload(obj, signatures, meta) => {
+                (Some(obj), signatures, Some(meta.kind.as_func_id()))
+            }
+            _ => return fallback(),
+        };
+        if signatures
+            .iter()
+            .any(|signature| !matches!(signature.params, Params::List(_)))
+        {
+            return fallback();
+        }
+        let self_arg = self_arg.as_ref().map(|obj| CallArg::Type(obj, range));
+        // Check the bound arguments by calling `f` with every parameter made optional.
+        let check_bound_args = |signature: &Callable, errors: &ErrorCollector| {
+            let Params::List(params) = &signature.params else {
+                return;
+            };
+            let params = params
+                .items()
+                .iter()
+                .map(|param| match param {
+                    Param::PosOnly(ty, _) => Param::PosOnly(ty.clone(), Required::Optional),
+                    Param::Pos(name, ty, _) => {
+                        Param::Pos(name.clone(), ty.clone(), Required::Optional)
+                    }
+                    Param::KwOnly(name, ty, _) => {
+                        Param::KwOnly(name.clone(), ty.clone(), Required::Optional)
+                    }
+                    _ => param.clone(),
+                })
+                .collect();
+            self.callable_infer(
+                Callable::list(ParamList::new(params), signature.ret.clone()),
+                func_id.clone(),
+                self_arg.clone(),
+                bound_args,
+                keywords,
+                range,
+                errors,
+                errors,
+                context,
+            );
+        };
+        let remaining = |signature: &Callable| {
+            let signature = match self_arg {
+                Some(_) => signature
+                    .drop_first_param()
+                    .unwrap_or_else(|| signature.clone()),
+                None => signature.clone(),
+            };
+            match &signature.params {
+                Params::List(params) => Callable::list(
+                    self.partial_remaining_params(params.items(), bound_args.len(), keywords),
+                    signature.ret,
+                ),
+                _ => signature,
+            }
+        };
+        // The result is still a `partial[R]`, but calling it takes the remaining parameters.
+        let partial_metadata = |ret: Type| FuncMetadata {
+            kind: FunctionKind::Partial(Box::new(ClassType::new(
+                cls.class_object().dupe(),
+                TArgs::new(vec![ret]),
+            ))),
+            flags: FuncFlags::default(),
+        };
+        let partial_function = |signature: Callable| {
+            Type::Function(Box::new(Function {
+                metadata: partial_metadata(signature.ret.clone()),
+                signature,
+            }))
+        };
+        let res = if signatures.len() == 1 {
+            check_bound_args(signatures.first(), errors);
+            partial_function(remaining(signatures.first()))
+        } else {
+            // Keep the overloads that accept the bound arguments.
+            let mut accepted = signatures
+                .iter()
+                .filter(|&signature| {
+                    let overload_errors = self.error_collector();
+                    check_bound_args(signature, &overload_errors);
+                    overload_errors.is_empty()
+                })
+                .map(remaining)
+                .collect::<Vec<_>>();
+            if accepted.len() == 1 {
+                partial_function(accepted.pop().unwrap())
+            } else if let Ok(accepted) = Vec1::try_from_vec(accepted) {
+                let ret = self.unions(accepted.iter().map(|sig| sig.ret.clone()).collect());
+                Type::Overload(Overload {
+                    signatures: accepted.mapped(OverloadType::Callable),
+                    metadata: Box::new(partial_metadata(ret)),
+                })
+            } else {
+                self.error(
+                    errors,
+                    range,
+                    ErrorKind::NoMatchingOverload,
+                    context,
+                    format!(
+                        "No overload of `{}` accepts the arguments bound by `functools.partial`",
+                        self.for_display(func_ty.clone()),
+                    ),
+                );
+                Type::any_error()
+            }
+        };
+        let res = self.solver().expand(res);
+        self.solver().finish_quantified(&call_target.qs);
+        res
+    }
+
+    /// The parameters left over after `functools.partial` binds `positional` arguments and
+    /// `keywords`. A parameter bound by keyword can still be overridden by keyword, and every
+    /// positional parameter after it can now only be passed by keyword.
+    fn partial_remaining_params(
+        &self,
+        params: &[Param],
+        positional: usize,
+        keywords: &[Keyword],
+    ) -> ParamList {
+        let is_bound = |name: &Name| {
+            keywords
+                .iter()
+                .any(|kw| kw.arg.as_ref().is_some_and(|id| id.id == *name))
+        };
+        let mut positional = positional;
+        let mut keyword_only = false;
+        let mut res = Vec::new();
+        for param in params {
+            match param {
+                Param::PosOnly(..) | Param::Pos(..) if positional > 0 => positional -= 1,
+                Param::Pos(name, ty, _) | Param::KwOnly(name, ty, _) if is_bound(name) => {
+                    keyword_only |= matches!(param, Param::Pos(..));
+                    res.push(Param::KwOnly(name.clone(), ty.clone(), Required::Optional));
+                }
+                Param::Pos(name, ty, required) if keyword_only => {
+                    res.push(Param::KwOnly(name.clone(), ty.clone(), *required));
+                }
+                // Extra positional arguments would collide with the keyword-bound parameter.
+                Param::VarArg(..) if keyword_only => {}
+                _ => res.push(param.clone()),
+            }
+        }
+        ParamList::new(res)
+    }
+
     fn construct_typed_dict(
         &self,
         typed_dict: TypedDict,
@@ -497,7 +729,18 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         context: Option<&dyn Fn() -> ErrorContext>,
         hint: Option<Type>,
     ) -> Type {
-        let is_dataclass = matches!(&call_target.target, Target::FunctionOverload(_, meta) if matches!(meta.kind, FunctionKind::Dataclass(_)));
+        let kind = match &call_target.target {
+            Target::Function(func) => Some(&func.metadata.kind),
+            Target::FunctionOverload(_, meta) => Some(&meta.kind),
+            _ => None,
+        };
+        // A decorator marked with `dataclass_transform` carries the defaults it was marked with,
+        // which explicit keywords then override.
+        let dataclass_defaults = match kind {
+            Some(FunctionKind::Dataclass(kws)) => Some((**kws).clone()),
+            _ => None,
+        };
+        let is_dataclass_transform = matches!(kind, Some(FunctionKind::DataclassTransform(_)));
         let res = match call_target.target {
             Target::Class(cls) => {
                 if let Some(hint) = hint {
@@ -526,7 +769,11 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                         Some(ty) => self.check_dunder_bool_is_callable(&ty, range, errors),
                     }
                 };
-                self.construct_class(cls, args, keywords, range, errors, context)
+                if cls.has_qname("functools", "partial") {
+                    self.construct_partial(cls, args, keywords, range, errors, context)
+                } else {
+                    self.construct_class(cls, args, keywords, range, errors, context)
+                }
             }
             Target::TypedDict(td) => {
                 self.construct_typed_dict(td, args, keywords, range, errors, context)
@@ -610,8 +857,9 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             }
         };
         self.solver().finish_quantified(&call_target.qs);
-        if is_dataclass && let Type::Callable(c) = res {
-            let mut kws = BoolKeywords::new();
+        if let Some(mut kws) = dataclass_defaults
+            && let Type::Callable(c) = res
+        {
             for kw in keywords {
                 kws.set_keyword(kw.arg.as_ref(), self.expr_infer(&kw.value, errors));
             }
@@ -622,11 +870,54 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                     flags: FuncFlags::default(),
                 },
             }))
+        } else if is_dataclass_transform {
+            let mut kws = BoolKeywords::new();
+            for kw in keywords {
+                if let Some(arg) = &kw.arg
+                    && let Some(name) = DataclassKeywords::from_transform_default(&arg.id)
+                    && let Type::Literal(Lit::Bool(value)) = self.expr_infer(&kw.value, errors)
+                {
+                    kws.set(name, value);
+                }
+            }
+            Type::Function(Box::new(Function {
+                signature: Callable::ellipsis(res),
+                metadata: FuncMetadata {
+                    kind: FunctionKind::DataclassTransform(Box::new(kws)),
+                    flags: FuncFlags::default(),
+                },
+            }))
         } else {
             res
         }
     }
 
+    /// Best-effort inference of what calling `callee` with the given arguments would return,
+    /// e.g. to show the result type of a call the user is still typing. Missing and mismatched
+    /// arguments are tolerated and no errors are reported; the arguments that are present
+    /// (positional and keyword alike) are still used to solve any type parameters in the return
+    /// type. Returns `None` if `callee` isn't callable.
+    #[cfg_attr(not(test), expect(dead_code))] // Not yet used by the IDE
+    pub fn infer_call_result(
+        &self,
+        callee: &Type,
+        args: &[CallArg],
+        keywords: &[Keyword],
+        range: TextRange,
+    ) -> Option<Type> {
+        let call_target = self.as_call_target(callee.clone())?;
+        let ret = self.call_infer(
+            call_target,
+            args,
+            keywords,
+            range,
+            &self.error_swallower(),
+            None,
+            None,
+        );
+        Some(self.solver().deep_force(ret))
+    }
+
     fn call_overloads(
         &self,
         overloads: Vec1<Callable>,
@@ -639,6 +930,8 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         context: Option<&dyn Fn() -> ErrorContext>,
     ) -> Type {
         let mut closest_overload: Option<CalledOverload> = None;
+        // Each overload we tried, with the first error it produced.
+        let mut attempts = Vec::new();
         for callable in overloads {
             let arg_errors = self.error_collector();
             let call_errors = self.error_collector();
@@ -663,6 +956,12 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 // See test::overload::test_pass_generic_class_to_overload for an example.
                 return res;
             }
+            attempts.push((
+                callable.clone(),
+                call_errors
+                    .first_message()
+                    .or_else(|| arg_errors.first_message()),
+            ));
             let called_overload = CalledOverload {
                 signature: callable,
                 arg_errors,
@@ -680,16 +979,15 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         // We're guaranteed to have at least one overload.
         let closest_overload = closest_overload.unwrap();
         errors.extend(closest_overload.arg_errors);
-        let signature = match self_arg {
-            Some(_) => closest_overload
-                .signature
-                .drop_first_param()
-                .unwrap_or(closest_overload.signature),
-            None => closest_overload.signature,
+        let display_signature = |signature: Callable| {
+            let signature = match self_arg {
+                Some(_) => signature.drop_first_param().unwrap_or(signature),
+                None => signature,
+            };
+            self.solver()
+                .for_display(Type::Callable(Box::new(signature)))
         };
-        let signature = self
-            .solver()
-            .for_display(Type::Callable(Box::new(signature)));
+        let signature = display_signature(closest_overload.signature);
         if closest_overload.call_errors.is_empty() {
             // No overload evaluated completely successfully, but we still say we found a match if
             // there were only arg_errors, since they may be unrelated. For example, in:
@@ -711,8 +1009,10 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 ErrorKind::NoMatchingOverload,
                 context,
                 format!(
-                    "No matching overload found for function `{}`, reporting errors for closest overload: `{}`",
-                    metadata.kind.as_func_id().format(self.module_info().name()), signature,
+                    "No matching overload found for function `{}`, reporting errors for closest overload: `{}`{}",
+                    metadata.kind.as_func_id().format(self.module_info().name()),
+                    signature,
+                    self.describe_overload_attempts(attempts, display_signature),
                 ),
             );
             errors.extend(closest_overload.call_errors);
@@ -720,6 +1020,28 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
     }
 
+    /// Describe every overload that was tried and why it failed, as extra lines of an error
+    /// message. Functions like `open` have many overloads, so only the first few are shown.
+    fn describe_overload_attempts(
+        &self,
+        attempts: Vec<(Callable, Option<String>)>,
+        display_signature: impl Fn(Callable) -> Type,
+    ) -> String {
+        const MAX_OVERLOADS_SHOWN: usize = 4;
+        let hidden = attempts.len().saturating_sub(MAX_OVERLOADS_SHOWN);
+        let mut res = String::new();
+        for (signature, error) in attempts.into_iter().take(MAX_OVERLOADS_SHOWN) {
+            res.push_str(&format!("\n  Overload `{}`", display_signature(signature)));
+            if let Some(error) = error {
+                res.push_str(&format!(": {error}"));
+            }
+        }
+        if hidden > 0 {
+            res.push_str(&format!("\n  ...and {hidden} more"));
+        }
+        res
+    }
+
     /// Helper function hide details of call synthesis from the attribute resolution code.
     pub fn call_property_getter(
         &self,
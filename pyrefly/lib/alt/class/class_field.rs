@@ -17,6 +17,7 @@ use ruff_python_ast::Arguments;
 use ruff_python_ast::Expr;
 use ruff_python_ast::ExprCall;
 use ruff_python_ast::name::Name;
+use ruff_text_size::Ranged;
 use ruff_text_size::TextRange;
 use starlark_map::small_map::SmallMap;
 use starlark_map::small_set::SmallSet;
@@ -27,6 +28,7 @@ use crate::alt::attr::Attribute;
 use crate::alt::attr::DescriptorBase;
 use crate::alt::attr::NoAccessReason;
 use crate::alt::types::class_metadata::ClassMetadata;
+use crate::binding::binding::Binding;
 use crate::binding::binding::ClassFieldInitialValue;
 use crate::binding::binding::ExprOrBinding;
 use crate::binding::binding::KeyClassField;
@@ -44,11 +46,13 @@ use crate::types::callable::FuncMetadata;
 use crate::types::callable::Function;
 use crate::types::callable::FunctionKind;
 use crate::types::callable::Param;
+use crate::types::callable::Params;
 use crate::types::callable::Required;
 use crate::types::class::Class;
 use crate::types::class::ClassType;
 use crate::types::class::Substitution;
 use crate::types::class::TArgs;
+use crate::types::display::TypeDisplayContext;
 use crate::types::literal::Lit;
 use crate::types::typed_dict::TypedDict;
 use crate::types::typed_dict::TypedDictField;
@@ -90,6 +94,18 @@ impl ClassFieldInitialization {
     }
 }
 
+/// What sort of member an attribute of a class is, e.g. for picking an icon in a symbol outline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeKind {
+    Method,
+    ClassMethod,
+    StaticMethod,
+    Property,
+    ClassVar,
+    InstanceVar,
+    NestedClass,
+}
+
 /// Raw information about an attribute declared somewhere in a class. We need to
 /// know whether it is initialized in the class body in order to determine
 /// both visibility rules and whether method binding should be performed.
@@ -209,8 +225,11 @@ impl ClassField {
         }
     }
 
-    pub fn as_param(self, name: &Name, default: bool, kw_only: bool) -> Param {
-        let ClassField(ClassFieldInner::Simple { ty, .. }) = self;
+    pub fn as_param(&self, name: &Name, default: bool, kw_only: bool) -> Param {
+        // An `InitVar[T]` field is passed to `__init__` as a plain `T`.
+        let ty = self
+            .dataclass_init_var_type()
+            .unwrap_or_else(|| self.raw_type().clone());
         let required = match default {
             true => Required::Optional,
             false => Required::Required,
@@ -308,6 +327,20 @@ impl ClassField {
         }
     }
 
+    /// For a dataclass field annotated with `dataclasses.InitVar[T]`, returns `T`.
+    pub fn dataclass_init_var_type(&self) -> Option<Type> {
+        match self.raw_type() {
+            Type::ClassType(cls) if cls.has_qname("dataclasses", "InitVar") => Some(
+                cls.targs()
+                    .as_slice()
+                    .first()
+                    .cloned()
+                    .unwrap_or_else(Type::any_implicit),
+            ),
+            _ => None,
+        }
+    }
+
     pub fn is_class_var(&self) -> bool {
         match &self.0 {
             ClassFieldInner::Simple { annotation, .. } => {
@@ -339,6 +372,41 @@ impl ClassField {
         }
     }
 
+    fn attribute_kind(&self) -> AttributeKind {
+        match &self.0 {
+            ClassFieldInner::Simple { ty, .. } => {
+                let metadata = match ty {
+                    Type::Function(box func)
+                    | Type::Forall(box Forall {
+                        body: Forallable::Function(func),
+                        ..
+                    }) => Some(&func.metadata),
+                    Type::Overload(overload) => Some(&*overload.metadata),
+                    _ => None,
+                };
+                if ty.is_property_getter() || ty.is_property_setter_with_getter().is_some() {
+                    AttributeKind::Property
+                } else if let Some(metadata) = metadata {
+                    if metadata.flags.is_staticmethod {
+                        AttributeKind::StaticMethod
+                    } else if metadata.flags.is_classmethod {
+                        AttributeKind::ClassMethod
+                    } else {
+                        AttributeKind::Method
+                    }
+                } else if matches!(ty, Type::ClassDef(_)) {
+                    AttributeKind::NestedClass
+                } else if self.is_class_var() {
+                    // A default value in the class body doesn't make it a class variable; that
+                    // takes an explicit `ClassVar`.
+                    AttributeKind::ClassVar
+                } else {
+                    AttributeKind::InstanceVar
+                }
+            }
+        }
+    }
+
     fn dataclass_flags_of(&self, kw_only: bool) -> BoolKeywords {
         match &self.0 {
             ClassFieldInner::Simple { initialization, .. } => {
@@ -632,6 +700,21 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             }
         }
 
+        if let Some(annotated_ty) = direct_annotation.and_then(|ann| ann.ty.as_ref()) {
+            self.check_property_matches_annotation(
+                name,
+                &value_ty,
+                value,
+                annotated_ty,
+                range,
+                errors,
+            );
+        }
+
+        if *name == dunder::POST_INIT && metadata.dataclass_metadata().is_some() {
+            self.check_dataclass_post_init(class, &value_ty, range, errors);
+        }
+
         // Determine whether this is an explicit `@override`.
         let is_override = value_ty.is_override();
 
@@ -781,6 +864,68 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         class_field
     }
 
+    /// If a class both annotates a name (e.g. `x: int`) and defines it as a property, the
+    /// property's getter has to return something compatible with the annotation.
+    fn check_property_matches_annotation(
+        &self,
+        name: &Name,
+        value_ty: &Type,
+        value: &ExprOrBinding,
+        annotated_ty: &Type,
+        range: TextRange,
+        errors: &ErrorCollector,
+    ) {
+        let mut getter = if value_ty.is_property_getter() {
+            value_ty.clone()
+        } else if let Some(getter) = value_ty.is_property_setter_with_getter() {
+            getter
+        } else {
+            return;
+        };
+        let Some(property_ty) = getter.callable_return_type() else {
+            return;
+        };
+        if self.is_subset_eq(&property_ty, annotated_ty) {
+            return;
+        }
+        let property_range = self.property_getter_return_range(value).unwrap_or(range);
+        let property_ty = self.for_display(property_ty);
+        let annotated_ty = self.for_display(annotated_ty.clone());
+        let ctx = TypeDisplayContext::new(&[&property_ty, &annotated_ty]);
+        self.error(
+            errors,
+            property_range,
+            ErrorKind::AnnotationMismatch,
+            None,
+            format!(
+                "Property `{}` returns `{}`, but `{}` is annotated with `{}` on line {}",
+                name,
+                ctx.display(&property_ty),
+                name,
+                ctx.display(&annotated_ty),
+                self.module_info().source_location(range.start()).row,
+            ),
+        );
+    }
+
+    /// The range of the return annotation of a property's getter, found by walking back from the
+    /// definition bound to the field (which may be a setter or deleter) to the getter.
+    fn property_getter_return_range(&self, value: &ExprOrBinding) -> Option<TextRange> {
+        let ExprOrBinding::Binding(Binding::Forward(mut idx)) = value else {
+            return None;
+        };
+        loop {
+            let Binding::Function(function_idx, pred, _) = self.bindings().get(idx) else {
+                return None;
+            };
+            if self.get_idx(idx).ty().is_property_getter() {
+                let def = &self.bindings().get(*function_idx).def;
+                return def.returns.as_ref().map(|returns| returns.range());
+            }
+            idx = (*pred)?;
+        }
+    }
+
     /// Return (did you find any fields, first one with an annotation)
     fn get_inherited_annotation(&self, class: &Class, name: &Name) -> (bool, Option<Annotation>) {
         let mut found_field = false;
@@ -1143,18 +1288,25 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                     self.is_subset_eq(got, want)
                 });
             if !attr_check {
-                self.error(
-                    errors,
-                    range,
-                    ErrorKind::BadOverride,
-                    None,
-                    format!(
-                        "Class member `{}.{}` overrides parent class `{}` in an inconsistent manner",
-                        class.name(),
-                        name,
-                        parent.name()
-                    ),
+                let mut msg = format!(
+                    "Class member `{}.{}` overrides parent class `{}` in an inconsistent manner",
+                    class.name(),
+                    name,
+                    parent.name()
                 );
+                let got_ty = class_field
+                    .instantiate_for(&Instance::of_class(&class.as_class_type()))
+                    .raw_type()
+                    .clone();
+                let want_ty = want_class_field
+                    .instantiate_for(&Instance::of_class(parent))
+                    .raw_type()
+                    .clone();
+                if let Some(detail) = self.describe_method_override_mismatch(&got_ty, &want_ty) {
+                    msg.push_str("\n  ");
+                    msg.push_str(&detail);
+                }
+                self.error(errors, range, ErrorKind::BadOverride, None, msg);
             }
         }
         if is_override && !parent_attr_found && !parent_has_any {
@@ -1172,6 +1324,80 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
     }
 
+    /// Pinpoint why an overriding method is incompatible with the method it overrides: a
+    /// parameter that accepts less than before, or a return type that promises less.
+    fn describe_method_override_mismatch(&self, got: &Type, want: &Type) -> Option<String> {
+        let (Type::Function(got), Type::Function(want)) = (got, want) else {
+            return None;
+        };
+        let (Params::List(got_params), Params::List(want_params)) =
+            (&got.signature.params, &want.signature.params)
+        else {
+            return None;
+        };
+        if got.metadata.flags.is_staticmethod != want.metadata.flags.is_staticmethod {
+            return None;
+        }
+        let skip = if got.metadata.flags.is_staticmethod {
+            0
+        } else {
+            1
+        };
+        let positional = |params: &[Param]| {
+            params
+                .iter()
+                .filter(|p| matches!(p, Param::PosOnly(..) | Param::Pos(..)))
+                .skip(skip)
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        let got_positional = positional(got_params.items());
+        let want_positional = positional(want_params.items());
+        let param_mismatch = |got_param: &Param, want_ty: &Type| {
+            let (got_name, got_ty) = match got_param {
+                Param::PosOnly(ty, _) => (None, ty),
+                Param::Pos(name, ty, _) | Param::KwOnly(name, ty, _) => (Some(name), ty),
+                Param::VarArg(_, ty) | Param::Kwargs(_, ty) => (None, ty),
+            };
+            if self.is_subset_eq(want_ty, got_ty) {
+                return None;
+            }
+            let ctx = TypeDisplayContext::new(&[got_ty, want_ty]);
+            let name = got_name.map_or_else(String::new, |name| format!(" `{name}`"));
+            Some(format!(
+                "Parameter{name} has type `{}`, which does not accept `{}` from the overridden method",
+                ctx.display(got_ty),
+                ctx.display(want_ty)
+            ))
+        };
+        for (got_param, want_param) in got_positional.iter().zip(want_positional.iter()) {
+            if let Param::PosOnly(want_ty, _) | Param::Pos(_, want_ty, _) = want_param
+                && let Some(detail) = param_mismatch(got_param, want_ty)
+            {
+                return Some(detail);
+            }
+        }
+        for want_param in want_params.items() {
+            if let Param::KwOnly(want_name, want_ty, _) = want_param
+                && let Some(got_param) = got_params.items().iter().find(|p| {
+                    matches!(p, Param::Pos(name, ..) | Param::KwOnly(name, ..) if name == want_name)
+                })
+                && let Some(detail) = param_mismatch(got_param, want_ty)
+            {
+                return Some(detail);
+            }
+        }
+        if !self.is_subset_eq(&got.signature.ret, &want.signature.ret) {
+            let ctx = TypeDisplayContext::new(&[&got.signature.ret, &want.signature.ret]);
+            return Some(format!(
+                "Return type `{}` is not assignable to `{}`, the return type of the overridden method",
+                ctx.display(&got.signature.ret),
+                ctx.display(&want.signature.ret)
+            ));
+        }
+        None
+    }
+
     fn get_non_synthesized_field_from_current_class_only(
         &self,
         cls: &Class,
@@ -1201,6 +1427,35 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
     }
 
+    /// Whether instances of `cls` are unhashable because `__hash__` is `None` on the class or an
+    /// ancestor, either written out or synthesized (e.g. for a dataclass with `eq=True`).
+    pub fn is_unhashable(&self, cls: &Class) -> bool {
+        self.get_class_member(cls, &dunder::HASH)
+            .is_some_and(|member| member.value.raw_type().is_none())
+    }
+
+    /// Classify the member `name` of `cls` (or one of its ancestors), e.g. as a method or a
+    /// property. Returns `None` if there is no such member.
+    #[cfg_attr(not(test), expect(dead_code))] // For the symbol outline, which doesn't exist yet
+    pub fn attribute_kind(&self, cls: &Class, name: &Name) -> Option<AttributeKind> {
+        self.get_class_member(cls, name)
+            .map(|member| member.value.attribute_kind())
+    }
+
+    /// Whether `name`, looked up on `cls` and its ancestors, is declared `Final`.
+    pub fn is_final_class_member(&self, cls: &Class, name: &Name) -> bool {
+        self.get_class_member(cls, name)
+            .is_some_and(|member| member.value.is_final())
+    }
+
+    /// The declared type of the member `name` on `cls` or one of its ancestors, as written
+    /// rather than as seen through an attribute access. Used to compare a class against its
+    /// declaration in a stub.
+    pub fn class_member_type(&self, cls: &Class, name: &Name) -> Option<Type> {
+        self.get_class_member(cls, name)
+            .map(|member| member.value.raw_type().clone())
+    }
+
     pub(in crate::alt::class) fn get_class_member(
         &self,
         cls: &Class,
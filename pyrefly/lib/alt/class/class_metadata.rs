@@ -161,6 +161,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         let mut named_tuple_metadata = None;
         let mut enum_metadata = None;
         let mut dataclass_metadata = None;
+        let mut dataclass_transform_metadata = None;
         let mut bases: Vec<BaseClass> = bases.map(|x| self.base_class_of(x, errors));
         if let Some(box special_base) = special_base {
             bases.push(special_base.clone());
@@ -251,6 +252,11 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                                 // itself decorated with @dataclass, we'll compute new metadata and overwrite this.
                                 dataclass_metadata = Some(base_dataclass.inherit());
                             }
+                            if dataclass_transform_metadata.is_none()
+                                && let Some(defaults) = base_class_metadata.dataclass_transform_metadata()
+                            {
+                                dataclass_transform_metadata = Some(defaults.clone());
+                            }
                             Some((c, base_class_metadata))
                         }
                         Some((Type::Tuple(Tuple::Concrete(ts)), _)) => {
@@ -335,7 +341,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 &Type::ClassType(metaclass.clone()),
                 &Type::ClassType(self.stdlib.enum_meta().clone()),
             ) {
-                if !cls.tparams().is_empty() {
+                if self.class_is_generic(cls) {
                     self.error(
                         errors,
                         cls.range(),
@@ -384,6 +390,28 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 );
             }
         }
+        if dataclass_transform_metadata.is_none()
+            && let Some(metaclass) = &metaclass
+            && let Some(defaults) = self
+                .get_metadata_for_class(metaclass.class_object())
+                .dataclass_transform_metadata()
+        {
+            dataclass_transform_metadata = Some(defaults.clone());
+        }
+        if let Some(defaults) = &dataclass_transform_metadata {
+            // Keywords in the class definition, e.g. `class C(ModelBase, frozen=False)`, override
+            // the defaults from `dataclass_transform`.
+            let mut kws = defaults.clone();
+            for (name, ty) in &keywords {
+                if let Type::Literal(Lit::Bool(value)) = ty {
+                    kws.set(name.clone(), *value);
+                }
+            }
+            dataclass_metadata = Some(DataclassMetadata {
+                fields: self.get_dataclass_fields(cls, &bases_with_metadata),
+                kws,
+            });
+        }
         let mut is_final = false;
         for decorator in decorators {
             let decorator = self.get_idx(*decorator);
@@ -398,6 +426,9 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 Some(CalleeKind::Function(FunctionKind::Final)) => {
                     is_final = true;
                 }
+                Some(CalleeKind::Function(FunctionKind::DataclassTransform(kws))) => {
+                    dataclass_transform_metadata = Some(*kws);
+                }
                 _ => {}
             }
         }
@@ -440,6 +471,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             enum_metadata,
             protocol_metadata,
             dataclass_metadata,
+            dataclass_transform_metadata,
             has_base_any,
             is_new_type,
             is_final,
@@ -525,7 +557,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
     }
 
-    pub fn class_tparams(
+    pub fn calculate_class_tparams(
         &self,
         name: &Identifier,
         scoped_tparams: Vec<TParamInfo>,
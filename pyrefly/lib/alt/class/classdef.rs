@@ -66,7 +66,8 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         let scoped_tparams = self.scoped_type_params(x.type_params.as_deref(), errors);
         let bases = bases.map(|x| self.base_class_of(x, errors));
         let name = &x.name;
-        let tparams_info = self.class_tparams(name, scoped_tparams, bases, legacy_tparams, errors);
+        let tparams_info =
+            self.calculate_class_tparams(name, scoped_tparams, bases, legacy_tparams, errors);
 
         let tparams = self.type_params(name.range, tparams_info, errors);
 
@@ -106,6 +107,17 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         self.get_enum_from_class(class_type.class_object())
     }
 
+    /// The type parameters of a class, whether declared with PEP 695 syntax or collected from
+    /// legacy `TypeVar`s in the bases, with their variance already resolved.
+    #[cfg_attr(not(test), expect(dead_code))] // For hover and documentation tooling
+    pub fn class_tparams<'c>(&self, cls: &'c Class) -> &'c TParams {
+        cls.tparams()
+    }
+
+    pub fn class_is_generic(&self, cls: &Class) -> bool {
+        !cls.tparams().is_empty()
+    }
+
     /// Creates default type arguments for a class, falling back to Any for type parameters without defaults.
     fn create_default_targs(
         &self,
@@ -168,7 +180,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             // Accept any number of arguments (by ignoring them).
             TArgs::default()
         } else {
-            self.check_and_create_targs(cls.name(), cls.tparams(), targs, range, errors)
+            self.check_and_create_targs(cls.name(), false, cls.tparams(), targs, range, errors)
         };
         self.type_of_instance(cls, targs)
     }
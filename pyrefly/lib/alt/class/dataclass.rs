@@ -8,6 +8,7 @@
 use std::sync::Arc;
 
 use ruff_python_ast::name::Name;
+use ruff_text_size::TextRange;
 use starlark_map::small_map::SmallMap;
 use starlark_map::small_set::SmallSet;
 
@@ -19,6 +20,8 @@ use crate::alt::types::class_metadata::ClassMetadata;
 use crate::alt::types::class_metadata::ClassSynthesizedField;
 use crate::alt::types::class_metadata::ClassSynthesizedFields;
 use crate::dunder;
+use crate::error::collector::ErrorCollector;
+use crate::error::kind::ErrorKind;
 use crate::types::callable::BoolKeywords;
 use crate::types::callable::Callable;
 use crate::types::callable::DataclassKeywords;
@@ -26,9 +29,11 @@ use crate::types::callable::FuncMetadata;
 use crate::types::callable::Function;
 use crate::types::callable::Param;
 use crate::types::callable::ParamList;
+use crate::types::callable::Params;
 use crate::types::callable::Required;
 use crate::types::class::Class;
 use crate::types::class::ClassType;
+use crate::types::display::TypeDisplayContext;
 use crate::types::literal::Lit;
 use crate::types::tuple::Tuple;
 use crate::types::types::Type;
@@ -94,6 +99,85 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         Some(ClassSynthesizedFields::new(fields))
     }
 
+    /// `__post_init__` is called with the values of the class's `InitVar` fields, in order, so
+    /// its parameters have to line up with them.
+    pub fn check_dataclass_post_init(
+        &self,
+        cls: &Class,
+        post_init: &Type,
+        range: TextRange,
+        errors: &ErrorCollector,
+    ) {
+        let Type::Function(func) = post_init else {
+            return;
+        };
+        let Params::List(params) = &func.signature.params else {
+            return;
+        };
+        let Some(dataclass) = self
+            .get_metadata_for_class(cls)
+            .dataclass_metadata()
+            .cloned()
+        else {
+            return;
+        };
+        let init_vars = self
+            .iter_fields(cls, &dataclass.fields)
+            .into_iter()
+            .filter_map(|(name, field, _)| Some((name, field.dataclass_init_var_type()?)))
+            .collect::<Vec<_>>();
+        // Skip `self`.
+        let params = params.items().get(1..).unwrap_or_default();
+        if params
+            .iter()
+            .any(|p| matches!(p, Param::VarArg(..) | Param::Kwargs(..)))
+        {
+            return;
+        }
+        let mut positional = params
+            .iter()
+            .filter(|p| matches!(p, Param::PosOnly(..) | Param::Pos(..)));
+        for (init_var, init_var_ty) in &init_vars {
+            let error = |msg| {
+                self.error(errors, range, ErrorKind::BadFunctionDefinition, None, msg);
+            };
+            match positional.next() {
+                None => error(format!(
+                    "`__post_init__` is missing a parameter for InitVar `{init_var}`"
+                )),
+                Some(Param::PosOnly(ty, _) | Param::Pos(_, ty, _))
+                    if !self.is_subset_eq(init_var_ty, ty) =>
+                {
+                    let ctx = TypeDisplayContext::new(&[init_var_ty, ty]);
+                    error(format!(
+                        "`__post_init__` parameter for InitVar `{init_var}` has type `{}`, which does not accept `{}`",
+                        ctx.display(ty),
+                        ctx.display(init_var_ty),
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+        for param in positional.chain(params.iter().filter(|p| matches!(p, Param::KwOnly(..)))) {
+            if let Param::PosOnly(_, Required::Required)
+            | Param::Pos(_, _, Required::Required)
+            | Param::KwOnly(_, _, Required::Required) = param
+            {
+                let name = match param {
+                    Param::Pos(name, ..) | Param::KwOnly(name, ..) => format!(" `{name}`"),
+                    _ => String::new(),
+                };
+                self.error(
+                    errors,
+                    range,
+                    ErrorKind::BadFunctionDefinition,
+                    None,
+                    format!("`__post_init__` parameter{name} does not correspond to any InitVar"),
+                );
+            }
+        }
+    }
+
     fn iter_fields(
         &self,
         cls: &Class,
@@ -25,10 +25,22 @@ use crate::types::types::Type;
 use crate::util::display::count;
 use crate::util::prelude::SliceExt;
 
+/// Describe the class or alias `name` that is being specialized, for use in error messages.
+fn describe_specialized(name: &Name, is_alias: bool) -> String {
+    if is_alias {
+        format!("type alias `{name}`")
+    } else {
+        format!("`{name}`")
+    }
+}
+
 impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
+    /// Match up type arguments with the type parameters of the class or type alias `name`,
+    /// filling in defaults for missing arguments and reporting a wrong number of arguments.
     pub fn check_and_create_targs(
         &self,
         name: &Name,
+        is_alias: bool,
         tparams: &TParams,
         targs: Vec<Type>,
         range: TextRange,
@@ -85,6 +97,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 // We've run out of arguments, and we have type parameters left to consume.
                 checked_targs.extend(self.consume_remaining_tparams(
                     name,
+                    is_alias,
                     tparams,
                     param_idx,
                     &checked_targs,
@@ -104,9 +117,9 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 ErrorKind::BadSpecialization,
                 None,
                 format!(
-                    "Expected {} for `{}`, got {}",
+                    "Expected {} for {}, got {}",
                     count(nparams, "type argument"),
-                    name,
+                    describe_specialized(name, is_alias),
                     nargs
                 ),
             );
@@ -317,6 +330,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
     fn consume_remaining_tparams(
         &self,
         name: &Name,
+        is_alias: bool,
         tparams: &TParams,
         param_idx: usize,
         checked_targs: &[Type],
@@ -336,9 +350,9 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 ErrorKind::BadSpecialization,
                 None,
                 format!(
-                    "Expected {} for `{}`, got {}",
+                    "Expected {} for {}, got {}",
                     count(tparams.len(), "type argument"),
-                    name,
+                    describe_specialized(name, is_alias),
                     nargs,
                 ),
             );
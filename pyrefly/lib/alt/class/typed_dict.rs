@@ -154,6 +154,14 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         })
     }
 
+    /// Whether `ty` is an instance of a TypedDict (after expanding any vars).
+    #[cfg_attr(not(test), expect(dead_code))] // Exposed for completion and validation tooling
+    pub fn is_typed_dict(&self, ty: &Type) -> bool {
+        matches!(self.solver().expand(ty.clone()), Type::TypedDict(_))
+    }
+
+    /// All the fields of a TypedDict, including those inherited from TypedDict bases, with
+    /// their types specialized to the TypedDict's type arguments.
     pub fn typed_dict_fields(&self, typed_dict: &TypedDict) -> SmallMap<Name, TypedDictField> {
         let class = typed_dict.class_object();
         let metadata = self.get_metadata_for_class(class);
@@ -167,6 +175,13 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             .collect()
     }
 
+    /// The fields of a TypedDict as returned by `typed_dict_fields`, as a list in definition
+    /// order, with inherited fields first.
+    #[cfg_attr(not(test), expect(dead_code))] // Exposed for completion and validation tooling
+    pub fn typed_dict_field_list(&self, typed_dict: &TypedDict) -> Vec<(Name, TypedDictField)> {
+        self.typed_dict_fields(typed_dict).into_iter().collect()
+    }
+
     pub fn typed_dict_field(&self, typed_dict: &TypedDict, name: &Name) -> Option<TypedDictField> {
         let class = typed_dict.class_object();
         let metadata = self.get_metadata_for_class(class);
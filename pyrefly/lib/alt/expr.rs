@@ -6,6 +6,7 @@
  */
 
 use dupe::Dupe;
+use itertools::Itertools;
 use num_traits::ToPrimitive;
 use ruff_python_ast::BoolOp;
 use ruff_python_ast::Comprehension;
@@ -46,6 +47,9 @@ use crate::types::callable::Param;
 use crate::types::callable::ParamList;
 use crate::types::callable::Params;
 use crate::types::callable::Required;
+use crate::types::class::Class;
+use crate::types::class::ClassKind;
+use crate::types::class::ClassType;
 use crate::types::lit_int::LitInt;
 use crate::types::literal::Lit;
 use crate::types::param_spec::ParamSpec;
@@ -59,11 +63,26 @@ use crate::types::type_var::TypeVar;
 use crate::types::type_var_tuple::TypeVarTuple;
 use crate::types::types::AnyStyle;
 use crate::types::types::CalleeKind;
+use crate::types::types::Forallable;
 use crate::types::types::Type;
 use crate::util::prelude::SliceExt;
 use crate::util::prelude::VecExt;
 use crate::util::visit::Visit;
 
+/// How applying a decorator changes the type of the function it decorates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecoratorEffect {
+    /// The function keeps its type, e.g. `@final`, `@override`, or a decorator typed as
+    /// `(F) -> F`.
+    Identity,
+    ClassMethod,
+    StaticMethod,
+    /// `@property`, or the `setter` of an existing property.
+    Property,
+    /// The decorator produces something else, whose type is given.
+    SignatureChanging(Type),
+}
+
 impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
     // Helper method for inferring the type of a boolean operation over a sequence of values.
     fn boolop(&self, values: &[Expr], op: BoolOp, errors: &ErrorCollector) -> Type {
@@ -725,6 +744,48 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
     }
 
+    /// Whether `cls` can't be instantiated because it has abstract methods that are not
+    /// implemented. Abstract methods only stop instantiation when the metaclass is `ABCMeta`.
+    pub fn is_abstract_class(&self, cls: &Class) -> bool {
+        let metadata = self.get_metadata_for_class(cls);
+        metadata
+            .metaclass()
+            .is_some_and(|metaclass| self.is_abc_meta(metaclass))
+            && !metadata.abstract_methods().is_empty()
+    }
+
+    fn check_not_abstract(&self, cls: &Class, range: TextRange, errors: &ErrorCollector) {
+        let metadata = self.get_metadata_for_class(cls);
+        // Protocols get their own error when they are instantiated.
+        if metadata.is_protocol() || !self.is_abstract_class(cls) {
+            return;
+        }
+        self.error(
+            errors,
+            range,
+            ErrorKind::InvalidArgument,
+            None,
+            format!(
+                "Cannot instantiate `{}` because it has unimplemented abstract methods: {}",
+                cls.name(),
+                metadata
+                    .abstract_methods()
+                    .iter()
+                    .map(|name| format!("`{name}`"))
+                    .join(", ")
+            ),
+        );
+    }
+
+    fn is_abc_meta(&self, metaclass: &ClassType) -> bool {
+        metaclass.has_qname("abc", "ABCMeta")
+            || self
+                .get_metadata_for_class(metaclass.class_object())
+                .ancestors_no_object()
+                .iter()
+                .any(|ancestor| ancestor.has_qname("abc", "ABCMeta"))
+    }
+
     /// Apply a decorator. This effectively synthesizes a function call.
     pub fn apply_decorator(
         &self,
@@ -746,7 +807,97 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         let call_target =
             self.as_call_target_or_error(ty_decorator, CallStyle::FreeForm, range, errors, None);
         let arg = CallArg::Type(&decoratee, range);
-        self.call_infer(call_target, &[arg], &[], range, errors, None, None)
+        let decorated = self.call_infer(call_target, &[arg], &[], range, errors, None, None);
+        if self.is_untyped_wraps_result(&decorated) {
+            decoratee
+        } else {
+            decorated
+        }
+    }
+
+    /// Classify what `decorator` does to `decoratee`. Decorators that function definitions
+    /// handle specially are recognized by their kind, everything else is applied the same way
+    /// `apply_decorator` does and compared with the original type.
+    #[cfg_attr(not(test), expect(dead_code))] // For annotating decorator lines in editors
+    pub fn resolve_decorator_effect(
+        &self,
+        decorator: Idx<Key>,
+        decoratee: &Type,
+    ) -> DecoratorEffect {
+        match self.get_idx(decorator).ty().callee_kind() {
+            Some(CalleeKind::Class(ClassKind::ClassMethod)) => DecoratorEffect::ClassMethod,
+            Some(CalleeKind::Class(ClassKind::StaticMethod)) => DecoratorEffect::StaticMethod,
+            Some(
+                CalleeKind::Class(ClassKind::Property)
+                | CalleeKind::Function(FunctionKind::PropertySetter(_)),
+            ) => DecoratorEffect::Property,
+            Some(
+                CalleeKind::Class(ClassKind::EnumMember)
+                | CalleeKind::Function(
+                    FunctionKind::Overload
+                    | FunctionKind::Override
+                    | FunctionKind::Final
+                    | FunctionKind::AbstractMethod
+                    | FunctionKind::DataclassTransform(_),
+                ),
+            ) => DecoratorEffect::Identity,
+            _ => {
+                let decorated = self.solver().deep_force(self.apply_decorator(
+                    decorator,
+                    decoratee.clone(),
+                    &self.error_swallower(),
+                ));
+                if &decorated == decoratee {
+                    DecoratorEffect::Identity
+                } else {
+                    DecoratorEffect::SignatureChanging(decorated)
+                }
+            }
+        }
+    }
+
+    /// A wrapper created with `functools.wraps` conventionally forwards its arguments to the
+    /// wrapped function. If the wrapper itself is untyped (`*args, **kwargs` returning `Any`),
+    /// the resulting `_Wrapped` says nothing useful about how to call it, so we take `wraps`
+    /// as a hint that the decorated function keeps its original signature.
+    fn is_untyped_wraps_result(&self, ty: &Type) -> bool {
+        if let Type::ClassType(cls) = ty
+            && cls.has_qname("functools", "_Wrapped")
+            && let [_, _, wrapper_params, wrapper_ret] = cls.targs().as_slice()
+        {
+            let gradual_params = match self.solver().expand(wrapper_params.clone()) {
+                Type::Ellipsis => true,
+                Type::ParamSpecValue(params) => !params.is_empty()
+                    && params.items().iter().all(
+                        |p| matches!(p, Param::VarArg(_, t) | Param::Kwargs(_, t) if t.is_any()),
+                    ),
+                _ => false,
+            };
+            gradual_params && self.solver().expand(wrapper_ret.clone()).is_any()
+        } else {
+            false
+        }
+    }
+
+    /// Error if `ty` is a class whose instances are unhashable (its `__hash__` is `None`), since
+    /// it is being used as a dict key, set element or mapping key.
+    fn check_hashable(&self, ty: &Type, range: TextRange, usage: &str, errors: &ErrorCollector) {
+        self.map_over_union(ty, |ty| {
+            if let Type::ClassType(cls) = ty
+                && self.is_unhashable(cls.class_object())
+            {
+                self.error(
+                    errors,
+                    range,
+                    ErrorKind::BadArgumentType,
+                    None,
+                    format!(
+                        "`{}` is unhashable and cannot be used as a {usage}",
+                        self.for_display(ty.clone())
+                    ),
+                );
+            }
+        });
     }
 
     /// Helper to infer element types for a list or set.
@@ -862,6 +1013,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                         xs.map(|x| self.expr_untype(x, TypeFormContext::TypeArgument, errors));
                     let targs = self.check_and_create_targs(
                         &forall.body.name(),
+                        matches!(forall.body, Forallable::TypeAlias(_)),
                         &forall.tparams,
                         tys,
                         range,
@@ -982,6 +1134,25 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                         Some(&|| ErrorContext::Index(self.for_display(base.clone()))),
                     )
                 }
+                Type::ClassType(ref cls)
+                    if self.has_superclass(
+                        cls.class_object(),
+                        &self.stdlib.mapping_class_object(),
+                    ) =>
+                {
+                    // Looking up a key hashes it, so check that it can be.
+                    let key_ty = self.expr_infer(slice, errors);
+                    self.check_hashable(&key_ty, slice.range(), "mapping key", errors);
+                    self.call_method_or_error(
+                        &base,
+                        &dunder::GETITEM,
+                        range,
+                        &[CallArg::Type(&key_ty, slice.range())],
+                        &[],
+                        errors,
+                        Some(&|| ErrorContext::Index(self.for_display(base.clone()))),
+                    )
+                }
                 Type::ClassType(_) => self.call_method_or_error(
                     &base,
                     &dunder::GETITEM,
@@ -1040,6 +1211,96 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         })
     }
 
+    /// The type of `base[index]` when only the type of the index is known, rather than the slice
+    /// expression. Handles literal indexes into tuples and named tuples, literal TypedDict keys,
+    /// `__class_getitem__` on class objects, and `__getitem__` on everything else.
+    #[cfg_attr(not(test), expect(dead_code))] // The checker itself always has the slice expression available
+    pub fn subscript_result_type(
+        &self,
+        base: &Type,
+        index: &Type,
+        range: TextRange,
+        errors: &ErrorCollector,
+    ) -> Type {
+        let literal_index = match index {
+            Type::Literal(lit) => lit.as_index_i64(),
+            _ => None,
+        };
+        let index_elements = |elts: &[Type], idx: i64| {
+            let elt_idx = if idx >= 0 {
+                idx
+            } else {
+                elts.len() as i64 + idx
+            };
+            match usize::try_from(elt_idx).ok().and_then(|i| elts.get(i)) {
+                Some(elt) => elt.clone(),
+                None => self.error(
+                    errors,
+                    range,
+                    ErrorKind::IndexError,
+                    None,
+                    format!(
+                        "Index {idx} out of range for tuple with {} elements",
+                        elts.len()
+                    ),
+                ),
+            }
+        };
+        self.distribute_over_union(base, |base| {
+            let base = match base {
+                Type::Var(v) => self.solver().force_var(*v),
+                _ => base.clone(),
+            };
+            let context = || ErrorContext::Index(self.for_display(base.clone()));
+            match &base {
+                Type::Any(style) => style.propagate(),
+                Type::Tuple(Tuple::Concrete(elts)) if let Some(idx) = literal_index => {
+                    index_elements(elts, idx)
+                }
+                Type::ClassType(cls)
+                    if let Some(idx) = literal_index
+                        && let Some(elts) = self.named_tuple_element_types(cls) =>
+                {
+                    index_elements(&elts, idx)
+                }
+                Type::TypedDict(typed_dict) if let Type::Literal(Lit::Str(key)) = index => {
+                    match self.typed_dict_field(typed_dict, &Name::new(key)) {
+                        Some(field) => field.ty,
+                        None => self.error(
+                            errors,
+                            range,
+                            ErrorKind::TypedDictKeyError,
+                            None,
+                            format!(
+                                "TypedDict `{}` does not have key `{}`",
+                                typed_dict.name(),
+                                key
+                            ),
+                        ),
+                    }
+                }
+                Type::ClassDef(_) => self.call_method_or_error(
+                    &base,
+                    &dunder::CLASS_GETITEM,
+                    range,
+                    &[CallArg::Type(index, range)],
+                    &[],
+                    errors,
+                    Some(&context),
+                ),
+                _ => self.call_method_or_error(
+                    &base,
+                    &dunder::GETITEM,
+                    range,
+                    &[CallArg::Type(index, range)],
+                    &[],
+                    errors,
+                    Some(&context),
+                ),
+            }
+        })
+    }
+
     /// This function should not be used directly: we want every expression to record a type trace,
     /// and that is handled in expr_infer_type_info_with_hint. This function should *only* be called
     /// via expr_infer_type_info_with_hint.
@@ -1241,6 +1502,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                                     key_hint.as_ref(),
                                     errors,
                                 );
+                                self.check_hashable(&key_t, key.range(), "dict key", errors);
                                 let value_t = self.expr_infer_with_hint_promote(
                                     &x.value,
                                     value_hint.as_ref(),
@@ -1305,6 +1567,9 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                     self.stdlib.set(elem_ty).to_type()
                 } else {
                     let elem_tys = self.elts_infer(&x.elts, elem_hint, errors);
+                    for (elt, ty) in x.elts.iter().zip(&elem_tys) {
+                        self.check_hashable(ty, elt.range(), "set element", errors);
+                    }
                     self.stdlib.set(self.unions(elem_tys)).to_type()
                 }
             }
@@ -1319,6 +1584,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 self.ifs_infer(&x.generators, errors);
                 self.ifs_infer(&x.generators, errors);
                 let elem_ty = self.expr_infer_with_hint_promote(&x.elt, elem_hint.as_ref(), errors);
+                self.check_hashable(&elem_ty, x.elt.range(), "set element", errors);
                 self.stdlib.set(elem_ty).to_type()
             }
             Expr::DictComp(x) => {
@@ -1326,6 +1592,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                     hint.map_or((None, None), |ty| self.decompose_dict(ty));
                 self.ifs_infer(&x.generators, errors);
                 let key_ty = self.expr_infer_with_hint_promote(&x.key, key_hint.as_ref(), errors);
+                self.check_hashable(&key_ty, x.key.range(), "dict key", errors);
                 let value_ty =
                     self.expr_infer_with_hint_promote(&x.value, value_hint.as_ref(), errors);
                 self.stdlib.dict(key_ty, value_ty).to_type()
@@ -1418,6 +1685,9 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                             ) {
                                 self.check_second_arg_is_class_object(x, errors);
                             }
+                            if let Type::ClassDef(cls) = ty {
+                                self.check_not_abstract(cls, func_range, errors);
+                            }
                             let args = x.arguments.args.map(|arg| match arg {
                                 Expr::Starred(x) => CallArg::Star(&x.value, x.range),
                                 _ => CallArg::Expr(arg),
@@ -1479,6 +1749,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                     .collect::<Vec<_>>();
                 let targs = self.check_and_create_targs(
                     &Name::new_static("slice"),
+                    false,
                     self.stdlib.slice_class_object().tparams(),
                     elts,
                     x.range(),
@@ -27,6 +27,7 @@ use crate::binding::binding::KeyClass;
 use crate::binding::binding::KeyClassMetadata;
 use crate::binding::binding::KeyFunction;
 use crate::binding::binding::KeyLegacyTypeParam;
+use crate::config::base::UntypedDefBehavior;
 use crate::dunder;
 use crate::error::collector::ErrorCollector;
 use crate::error::context::TypeCheckContext;
@@ -42,6 +43,7 @@ use crate::types::callable::Function;
 use crate::types::callable::FunctionKind;
 use crate::types::callable::Param;
 use crate::types::callable::ParamList;
+use crate::types::callable::Params;
 use crate::types::callable::Required;
 use crate::types::class::ClassKind;
 use crate::types::class::ClassType;
@@ -51,6 +53,7 @@ use crate::types::types::Forallable;
 use crate::types::types::Overload;
 use crate::types::types::OverloadType;
 use crate::types::types::Type;
+use crate::util::prelude::SliceExt;
 
 impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
     pub fn solve_function_binding(
@@ -142,6 +145,27 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
     }
 
+    /// The type of an unannotated parameter, based on its default. We only look at literals
+    /// other than `None`, and widen them to their class, so `x=0` gives `int`.
+    fn param_type_from_default(&self, default: &Expr) -> Option<Type> {
+        let is_literal = match default {
+            Expr::NumberLiteral(_)
+            | Expr::StringLiteral(_)
+            | Expr::BytesLiteral(_)
+            | Expr::BooleanLiteral(_) => true,
+            Expr::UnaryOp(x) => matches!(&*x.operand, Expr::NumberLiteral(_)),
+            _ => false,
+        };
+        if is_literal {
+            Some(
+                self.expr_infer(default, &self.error_swallower())
+                    .promote_literals(self.stdlib),
+            )
+        } else {
+            None
+        }
+    }
+
     pub fn function_definition(
         &self,
         def: &StmtFunctionDef,
@@ -190,6 +214,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         let mut has_enum_member_decoration = false;
         let mut is_override = false;
         let mut has_final_decoration = false;
+        let mut dataclass_transform_defaults = None;
         let decorators = decorators
             .iter()
             .filter(|k| {
@@ -230,6 +255,10 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                         has_final_decoration = true;
                         false
                     }
+                    Some(CalleeKind::Function(FunctionKind::DataclassTransform(kws))) => {
+                        dataclass_transform_defaults = Some(kws);
+                        false
+                    }
                     _ => true,
                 }
             })
@@ -244,18 +273,25 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             self_type = self_type.map(Type::type_form);
         }
 
-        let mut get_param_ty = |name: &Identifier| {
+        let mut get_param_ty = |name: &Identifier, default: Option<&Expr>| {
             let ty = match self.bindings().get_function_param(name) {
                 Either::Left(idx) => self.get_idx(idx).annotation.get_type().clone(),
                 Either::Right(var) => {
                     // If this is the first parameter and there is a self type, solve to `Self`.
-                    // We only try to solve the first param for now. Other unannotated params
-                    // are also Var, but will always be forced to Any. In the future, we might
-                    // consider contextual information to infer parameter types, like decorator
-                    // applications.
+                    // Other unannotated params are also Var. If they have a literal default and
+                    // we infer types for untyped defs, we solve to its widened type, otherwise they
+                    // will be forced to Any. In the future, we might consider contextual
+                    // information to infer parameter types, like decorator applications.
                     if let Some(ty) = &self_type {
                         self.solver()
                             .is_subset_eq(&var.to_type(), ty, self.type_order());
+                    } else if self.bindings().untyped_def_behavior()
+                        == UntypedDefBehavior::CheckAndInferReturnType
+                        && let Some(default) = default
+                        && let Some(ty) = self.param_type_from_default(default)
+                    {
+                        self.solver()
+                            .is_subset_eq(&var.to_type(), &ty, self.type_order());
                     }
                     self.solver().force_var(var)
                 }
@@ -267,17 +303,17 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         let mut paramspec_kwargs = None;
         let mut params = Vec::with_capacity(def.parameters.len());
         params.extend(def.parameters.posonlyargs.iter().map(|x| {
-            let ty = get_param_ty(&x.parameter.name);
+            let ty = get_param_ty(&x.parameter.name, x.default.as_deref());
             let required = check_default(&x.parameter.name, &x.default, &ty);
             Param::PosOnly(ty, required)
         }));
         params.extend(def.parameters.args.iter().map(|x| {
-            let ty = get_param_ty(&x.parameter.name);
+            let ty = get_param_ty(&x.parameter.name, x.default.as_deref());
             let required = check_default(&x.parameter.name, &x.default, &ty);
             Param::Pos(x.parameter.name.id.clone(), ty, required)
         }));
         params.extend(def.parameters.vararg.iter().map(|x| {
-            let ty = get_param_ty(&x.name);
+            let ty = get_param_ty(&x.name, None);
             if let Type::Args(q) = &ty {
                 paramspec_args = Some(q.clone());
             }
@@ -298,7 +334,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             );
         }
         params.extend(def.parameters.kwonlyargs.iter().map(|x| {
-            let ty = get_param_ty(&x.parameter.name);
+            let ty = get_param_ty(&x.parameter.name, x.default.as_deref());
             let required = check_default(&x.parameter.name, &x.default, &ty);
             Param::KwOnly(x.parameter.name.id.clone(), ty, required)
         }));
@@ -385,11 +421,16 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         } else {
             Callable::list(ParamList::new(params), ret)
         };
-        let kind = FunctionKind::from_name(
-            self.module_info().name(),
-            defining_cls.as_ref().map(|cls| cls.name()),
-            &def.name.id,
-        );
+        // A function marked with `@dataclass_transform` is a decorator that behaves like
+        // `@dataclass`, with whatever defaults the marker specified.
+        let kind = match dataclass_transform_defaults {
+            Some(kws) => FunctionKind::Dataclass(kws),
+            None => FunctionKind::from_name(
+                self.module_info().name(),
+                defining_cls.as_ref().map(|cls| cls.name()),
+                &def.name.id,
+            ),
+        };
         let metadata = FuncMetadata {
             kind,
             flags: FuncFlags {
@@ -483,6 +524,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         ts: Vec1<(TextRange, Type)>,
         errors: &ErrorCollector,
     ) -> Vec1<OverloadType> {
+        self.check_unreachable_overloads(&ts, errors);
         ts.mapped(|(range, t)| match t {
             Type::Callable(box callable) => OverloadType::Callable(callable),
             Type::Function(function) => OverloadType::Callable(function.signature),
@@ -512,4 +554,51 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             }
         })
     }
+
+    /// Overloads are tried from top to bottom, so an overload can never be selected if an
+    /// earlier overload accepts every call that it accepts.
+    fn check_unreachable_overloads(&self, ts: &[(TextRange, Type)], errors: &ErrorCollector) {
+        // Compare parameters only, by giving every signature the same return type.
+        let object = self.stdlib.object().clone().to_type();
+        let params_only = ts.map(|(range, t)| {
+            let params = match t {
+                Type::Callable(box callable) => Some(callable.params.clone()),
+                Type::Function(box func) => Some(func.signature.params.clone()),
+                _ => None,
+            };
+            let callable = params
+                .filter(|params| matches!(params, Params::List(_)))
+                .map(|params| {
+                    Type::Callable(Box::new(Callable {
+                        params,
+                        ret: object.clone(),
+                    }))
+                });
+            (*range, callable)
+        });
+        for (i, (later_range, later)) in params_only.iter().enumerate() {
+            // With gradual parameter types, the subset check can't tell us anything.
+            let Some(later) = later.as_ref().filter(|later| !later.any(|t| t.is_any())) else {
+                continue;
+            };
+            if let Some((earlier_range, _)) = params_only[..i].iter().find(|(_, earlier)| {
+                earlier
+                    .as_ref()
+                    .is_some_and(|earlier| self.is_subset_eq(earlier, later))
+            }) {
+                self.error(
+                    errors,
+                    *later_range,
+                    ErrorKind::UnreachableOverload,
+                    None,
+                    format!(
+                        "Overload is unreachable because the earlier overload at line {} is more general",
+                        self.module_info()
+                            .source_location(earlier_range.start())
+                            .row,
+                    ),
+                );
+            }
+        }
+    }
 }
@@ -28,14 +28,18 @@ use crate::binding::narrow::FacetChain;
 use crate::binding::narrow::FacetKind;
 use crate::binding::narrow::NarrowOp;
 use crate::error::collector::ErrorCollector;
+use crate::error::kind::ErrorKind;
 use crate::types::callable::FunctionKind;
 use crate::types::class::ClassType;
 use crate::types::literal::Lit;
 use crate::types::tuple::Tuple;
 use crate::types::type_info::TypeInfo;
 use crate::types::types::CalleeKind;
+use crate::types::types::Forall;
+use crate::types::types::Forallable;
 use crate::types::types::Type;
 use crate::util::prelude::SliceExt;
+use crate::util::visit::Visit;
 
 /// Beyond this size, don't try and narrow an enum.
 ///
@@ -44,6 +48,28 @@ use crate::util::prelude::SliceExt;
 /// is very high.
 const NARROW_ENUM_LIMIT: usize = 100;
 
+/// Conditions written as literals, like `while True:`, are deliberate, so we don't warn that
+/// their truthiness is known.
+fn is_literal_condition(test: &Expr) -> bool {
+    matches!(
+        test,
+        Expr::BooleanLiteral(_)
+            | Expr::NumberLiteral(_)
+            | Expr::StringLiteral(_)
+            | Expr::BytesLiteral(_)
+            | Expr::NoneLiteral(_)
+            | Expr::EllipsisLiteral(_)
+    )
+}
+
+/// What we know about the result of calling `bool()` on a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Truthiness {
+    AlwaysTrue,
+    AlwaysFalse,
+    Ambiguous,
+}
+
 impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
     // Get the union of all members of an enum, minus the specified member
     fn subtract_enum_member(&self, cls: &ClassType, name: &Name) -> Type {
@@ -118,6 +144,50 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         })
     }
 
+    /// Split a type into the finite set of values it stands for, where we know it: an enum
+    /// becomes the union of its members and `bool` becomes `Literal[True, False]`.
+    fn expand_to_members(&self, ty: &Type) -> Type {
+        self.distribute_over_union(ty, |t| match t {
+            Type::ClassType(cls) if cls.is_builtin("bool") => self.unions(vec![
+                Type::Literal(Lit::Bool(true)),
+                Type::Literal(Lit::Bool(false)),
+            ]),
+            Type::ClassType(cls)
+                if cls.class_object().fields().len() <= NARROW_ENUM_LIMIT
+                    && self
+                        .get_enum_from_class_type(cls)
+                        .is_some_and(|e| !e.is_flag) =>
+            {
+                self.unions(
+                    self.get_enum_members(cls.class_object())
+                        .into_iter()
+                        .map(Type::Literal)
+                        .collect(),
+                )
+            }
+            _ => t.clone(),
+        })
+    }
+
+    /// What is left of `subject` once every pattern in `covered` has been matched against it,
+    /// where each pattern is given as the type it matches: a literal, an enum member, `None`, or
+    /// a class (for class patterns). Returns `Never` when the patterns are exhaustive.
+    #[cfg_attr(not(test), expect(dead_code))] // For match exhaustiveness diagnostics
+    pub fn remaining_after_patterns(&self, subject: &Type, covered: &[Type]) -> Type {
+        covered
+            .iter()
+            .fold(self.expand_to_members(subject), |remaining, pattern| {
+                self.subtract(&remaining, pattern)
+            })
+    }
+
+    /// Do the given patterns cover every member of the enum `cls`?
+    #[cfg_attr(not(test), expect(dead_code))] // For match exhaustiveness diagnostics
+    pub fn enum_is_exhaustive(&self, cls: &ClassType, covered: &[Type]) -> bool {
+        self.remaining_after_patterns(&Type::ClassType(cls.clone()), covered)
+            .is_never()
+    }
+
     fn resolve_narrowing_call(
         &self,
         func: &Expr,
@@ -642,4 +712,111 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             ),
         }
     }
+
+    /// Whether values of type `ty` are always truthy, always falsy, or could be either.
+    /// Beyond what `Type::as_bool` knows about literals, `None` and tuples, functions, classes
+    /// and modules are always truthy.
+    pub fn truthiness(&self, ty: &Type) -> Truthiness {
+        match ty {
+            Type::ClassDef(_)
+            | Type::Function(_)
+            | Type::BoundMethod(_)
+            | Type::Overload(_)
+            | Type::Forall(box Forall {
+                body: Forallable::Function(_),
+                ..
+            })
+            | Type::Module(_) => Truthiness::AlwaysTrue,
+            Type::Union(options) => {
+                let mut options = options.iter().map(|x| self.truthiness(x));
+                let first = options.next().unwrap_or(Truthiness::Ambiguous);
+                if options.all(|x| x == first) {
+                    first
+                } else {
+                    Truthiness::Ambiguous
+                }
+            }
+            _ => match ty.as_bool() {
+                Some(true) => Truthiness::AlwaysTrue,
+                Some(false) => Truthiness::AlwaysFalse,
+                None => Truthiness::Ambiguous,
+            },
+        }
+    }
+
+    /// The type of `bool(x)` for `x: ty`: `Literal[True]`, `Literal[False]`, or `bool`.
+    #[cfg_attr(not(test), expect(dead_code))] // For hover on conditions
+    pub fn coerce_to_bool(&self, ty: &Type) -> Type {
+        match self.truthiness(ty) {
+            Truthiness::AlwaysTrue => Type::Literal(Lit::Bool(true)),
+            Truthiness::AlwaysFalse => Type::Literal(Lit::Bool(false)),
+            Truthiness::Ambiguous => self.stdlib.bool().clone().to_type(),
+        }
+    }
+
+    /// Warn about an `if` or `while` condition whose truthiness is known statically.
+    pub fn check_constant_condition(&self, test: &Expr, ty: &Type, errors: &ErrorCollector) {
+        if is_literal_condition(test) {
+            return;
+        }
+        let always = match self.truthiness(ty) {
+            Truthiness::AlwaysTrue => "true",
+            Truthiness::AlwaysFalse => "false",
+            Truthiness::Ambiguous => return,
+        };
+        self.error(
+            errors,
+            test.range(),
+            ErrorKind::ConstantCondition,
+            None,
+            format!(
+                "Condition is always {always}, since it has type `{}`",
+                self.for_display(ty.clone())
+            ),
+        );
+    }
+
+    /// Warn about the branches of an `if` statement that a condition with known truthiness makes
+    /// unreachable: its own body if it is always false, or the clauses after it if it is always
+    /// true. If the condition assigns with a walrus, the branch can't simply be deleted, so we
+    /// don't suggest doing so.
+    pub fn check_unreachable_branch(
+        &self,
+        test: &Expr,
+        ty: &Type,
+        body: TextRange,
+        later_clauses: Option<TextRange>,
+        errors: &ErrorCollector,
+    ) {
+        fn contains_walrus(x: &Expr) -> bool {
+            let mut found = matches!(x, Expr::Named(_));
+            x.recurse(&mut |x| found = found || contains_walrus(x));
+            found
+        }
+        if is_literal_condition(test) {
+            return;
+        }
+        let (range, always) = match self.truthiness(ty) {
+            Truthiness::AlwaysTrue => match later_clauses {
+                Some(range) => (range, "true"),
+                None => return,
+            },
+            Truthiness::AlwaysFalse => (body, "false"),
+            Truthiness::Ambiguous => return,
+        };
+        let suggestion = if contains_walrus(test) {
+            ""
+        } else {
+            ", consider removing it"
+        };
+        self.error(
+            errors,
+            range,
+            ErrorKind::UnreachableBranch,
+            None,
+            format!(
+                "This branch is unreachable because the condition is always {always}{suggestion}"
+            ),
+        );
+    }
 }
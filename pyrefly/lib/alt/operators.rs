@@ -29,6 +29,8 @@ use crate::error::context::TypeCheckContext;
 use crate::error::context::TypeCheckKind;
 use crate::error::kind::ErrorKind;
 use crate::graph::index::Idx;
+use crate::types::callable::Param;
+use crate::types::callable::Required;
 use crate::types::literal::Lit;
 use crate::types::tuple::Tuple;
 use crate::types::types::Type;
@@ -306,6 +308,132 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         result
     }
 
+    /// Whether `ty` is an ordinary value, as opposed to a class object or something we know little about.
+    fn is_plain_value(&self, ty: &Type) -> bool {
+        match ty {
+            Type::Union(ts) => ts.iter().all(|t| self.is_plain_value(t)),
+            Type::Literal(_) | Type::None => true,
+            Type::ClassType(cls) => !self.has_superclass(
+                cls.class_object(),
+                self.stdlib.builtins_type().class_object(),
+            ),
+            _ => false,
+        }
+    }
+
+    /// Warn about `x == C`, where `x` is a value and `C` is a class, which is almost always
+    /// a typo for `isinstance(x, C)`. If the value's `__eq__` doesn't accept an arbitrary
+    /// `object`, it has been overridden to do something special, and we leave it alone.
+    fn check_compare_to_class(
+        &self,
+        op: CmpOp,
+        left: &Type,
+        right: &Type,
+        range: TextRange,
+        errors: &ErrorCollector,
+    ) {
+        let Type::ClassDef(cls) = right else {
+            return;
+        };
+        if !matches!(op, CmpOp::Eq | CmpOp::NotEq) || !self.is_plain_value(left) {
+            return;
+        }
+        let generic_eq = Type::callable(
+            vec![Param::PosOnly(
+                self.stdlib.object().clone().to_type(),
+                Required::Required,
+            )],
+            self.stdlib.object().clone().to_type(),
+        );
+        if let Some(eq) = self.type_of_magic_dunder_attr(
+            left,
+            &dunder::EQ,
+            range,
+            &self.error_swallower(),
+            None,
+            "check_compare_to_class",
+        ) && !self.is_subset_eq(&eq, &generic_eq)
+        {
+            return;
+        }
+        let negate = if op == CmpOp::NotEq { "not " } else { "" };
+        self.error(
+            errors,
+            range,
+            ErrorKind::CompareToClass,
+            None,
+            format!(
+                "Comparing a value of type `{}` to the class `{}` with `{}`, did you mean `{negate}isinstance(...)`?",
+                self.for_display(left.clone()),
+                cls.name(),
+                op.as_str(),
+            ),
+        );
+    }
+
+    /// Warn about `x in y` where `x` can never be equal to an element of `y`, e.g.
+    /// `"x" in [1, 2, 3]`. Most containers declare `__contains__(self, key: object)`, so
+    /// this isn't caught by the call itself.
+    fn check_non_overlapping_container(
+        &self,
+        op: CmpOp,
+        left: &Type,
+        right: &Type,
+        range: TextRange,
+        errors: &ErrorCollector,
+    ) {
+        if !self
+            .bindings()
+            .is_error_enabled(ErrorKind::NonOverlappingContainer)
+            || !self.is_plain_value(left)
+        {
+            return;
+        }
+        // If `__contains__` doesn't take any `object`, as with `str` and `bytes`, it already
+        // checked `left`, and what it accepts needn't match what iterating produces.
+        let object = self.stdlib.object().clone().to_type();
+        let object_errors = self.error_collector();
+        self.call_magic_dunder_method(
+            right,
+            &dunder::CONTAINS,
+            range,
+            &[CallArg::Type(&object, range)],
+            &[],
+            &object_errors,
+            None,
+        );
+        if !object_errors.is_empty() {
+            return;
+        }
+        let iterables = self.iterate(right, range, &self.error_swallower());
+        let overlaps = |ty: &Type| {
+            ty.any(|t| t.is_type_variable())
+                || self.is_subset_eq(left, ty)
+                || self.is_subset_eq(ty, left)
+        };
+        if iterables.is_empty()
+            || iterables.iter().any(|iterable| match iterable {
+                Iterable::OfType(ty) => overlaps(ty),
+                Iterable::FixedLen(ts) => ts.iter().any(overlaps),
+            })
+        {
+            return;
+        }
+        self.error(
+            errors,
+            range,
+            ErrorKind::NonOverlappingContainer,
+            None,
+            format!(
+                "`{}` is always {} because `{}` can never be an element of `{}`",
+                op.as_str(),
+                if op == CmpOp::In { "false" } else { "true" },
+                self.for_display(left.clone()),
+                self.for_display(right.clone()),
+            ),
+        );
+    }
+
     pub fn compare_infer(&self, x: &ExprCompare, errors: &ErrorCollector) -> Type {
         let left = self.expr_infer(&x.left, errors);
         let comparisons = x.ops.iter().zip(x.comparators.iter());
@@ -313,6 +441,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             comparisons
                 .map(|(op, comparator)| {
                     let right = self.expr_infer(comparator, errors);
+                    self.check_compare_to_class(*op, &left, &right, x.range, errors);
                     self.distribute_over_union(&left, |left| {
                         self.distribute_over_union(&right, |right| {
                             let context = || {
@@ -331,16 +460,25 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                                     // See https://docs.python.org/3/reference/expressions.html#membership-test-operations.
                                     // `x in y` first tries `y.__contains__(x)`, then checks if `x` matches an element
                                     // obtained by iterating over `y`.
-                                    if let Some(ret) = self.call_magic_dunder_method(
+                                    let contains_errors = self.error_collector();
+                                    let contains_ret = self.call_magic_dunder_method(
                                         right,
                                         &dunder::CONTAINS,
                                         x.range,
                                         &[CallArg::Type(left, x.left.range())],
                                         &[],
-                                        errors,
+                                        &contains_errors,
                                         Some(&context),
-                                    ) {
+                                    );
+                                    let contains_ok = contains_errors.is_empty();
+                                    errors.extend(contains_errors);
+                                    if let Some(ret) = contains_ret {
                                         // Comparison method called.
+                                        if contains_ok {
+                                            self.check_non_overlapping_container(
+                                                *op, left, right, x.range, errors,
+                                            );
+                                        }
                                         ret
                                     } else {
                                         let iteration_errors = self.error_collector();
@@ -7,12 +7,15 @@
 
 use std::iter;
 use std::mem;
+use std::path::Path;
 use std::sync::Arc;
 
 use dupe::Dupe;
 use itertools::Either;
 use ruff_python_ast::Expr;
+use ruff_python_ast::ExprBinOp;
 use ruff_python_ast::ExprSubscript;
+use ruff_python_ast::Operator;
 use ruff_python_ast::TypeParam;
 use ruff_python_ast::TypeParams;
 use ruff_python_ast::name::Name;
@@ -71,6 +74,8 @@ use crate::error::context::ErrorContext;
 use crate::error::context::TypeCheckContext;
 use crate::error::context::TypeCheckKind;
 use crate::error::kind::ErrorKind;
+use crate::export::exports::ExportLocation;
+use crate::graph::index::Idx;
 use crate::module::short_identifier::ShortIdentifier;
 use crate::ruff::ast::Ast;
 use crate::types::annotation::Annotation;
@@ -89,6 +94,7 @@ use crate::types::param_spec::ParamSpec;
 use crate::types::quantified::Quantified;
 use crate::types::quantified::QuantifiedInfo;
 use crate::types::quantified::QuantifiedKind;
+use crate::types::special_form::SpecialForm;
 use crate::types::tuple::Tuple;
 use crate::types::type_info::TypeInfo;
 use crate::types::type_var::PreInferenceVariance;
@@ -97,6 +103,7 @@ use crate::types::type_var::TypeVar;
 use crate::types::type_var_tuple::TypeVarTuple;
 use crate::types::types::AnyStyle;
 use crate::types::types::CalleeKind;
+use crate::types::types::Forall;
 use crate::types::types::Forallable;
 use crate::types::types::SuperObj;
 use crate::types::types::TParam;
@@ -375,6 +382,189 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         false
     }
 
+    /// Warn when the explicit returns of an unannotated function have no common base besides
+    /// `object`, e.g. one `return` gives an `int` and another a `str`. Returning `None` alongside
+    /// something else is a deliberate optional result, so `None` is left out of the comparison.
+    fn check_inconsistent_returns(
+        &self,
+        returns: &[Type],
+        range: TextRange,
+        errors: &ErrorCollector,
+    ) {
+        if !self
+            .bindings()
+            .is_error_enabled(ErrorKind::InconsistentReturns)
+        {
+            return;
+        }
+        let mut seen: Vec<&Type> = Vec::new();
+        for ty in returns {
+            // An unsolved variable (e.g. the element type of `[]`) may yet agree with the rest.
+            if ty.any(|t| matches!(t, Type::Var(_))) {
+                return;
+            }
+            match ty {
+                Type::ClassType(_) | Type::Literal(_) => {
+                    if !seen.contains(&ty) {
+                        seen.push(ty);
+                    }
+                }
+                _ if ty.is_none() || ty.is_never() => {}
+                // Anything else (`Any`, unions, callables, ...) is too
+                // imprecise to call inconsistent.
+                _ => return,
+            }
+        }
+        let Some((first, rest)) = seen.split_first() else {
+            return;
+        };
+        let mut joined = (*first).clone();
+        for ty in rest {
+            let next = self.common_supertype(&joined, ty);
+            if matches!(next, Type::Union(_)) {
+                self.error(
+                    errors,
+                    range,
+                    ErrorKind::InconsistentReturns,
+                    None,
+                    format!(
+                        "Function returns both `{}` and `{}`, which have no common base other than `object`, consider adding a return annotation",
+                        self.for_display(joined.promote_literals(self.stdlib)),
+                        self.for_display((*ty).clone().promote_literals(self.stdlib)),
+                    ),
+                );
+                return;
+            }
+            joined = next;
+        }
+    }
+
+    /// Warn about `Literal` members of a union annotation that another member already covers,
+    /// e.g. `int | Literal[3]` or `Literal["a", "a"]`. Building the union simplifies these away,
+    /// so we look at the members as they are written.
+    fn check_redundant_literals(&self, x: &Expr, ann_ty: &Type, errors: &ErrorCollector) {
+        if !self
+            .bindings()
+            .is_error_enabled(ErrorKind::RedundantLiteral)
+        {
+            return;
+        }
+        // Once simplified, a union containing a literal is still a union or a literal, or has
+        // collapsed into the class that absorbed the literal, which is never generic. Anything
+        // else (e.g. `list[int]`) can't be redundant, so we don't look at it again.
+        match ann_ty {
+            Type::Union(_) | Type::Literal(_) | Type::LiteralString => {}
+            Type::ClassType(cls) if cls.targs().is_empty() => {}
+            _ => return,
+        }
+        let swallower = self.error_swallower();
+        let mut members = Vec::new();
+        match x {
+            Expr::BinOp(ExprBinOp {
+                op: Operator::BitOr,
+                ..
+            }) => self.collect_union_members(x, &swallower, &mut members),
+            Expr::Subscript(s) => {
+                let value_ty = self.expr_infer(&s.value, &swallower);
+                if !matches!(
+                    value_ty,
+                    Type::Type(box Type::SpecialForm(SpecialForm::Union | SpecialForm::Literal))
+                ) {
+                    return;
+                }
+                self.collect_subscript_members(x, s, value_ty, &swallower, &mut members);
+            }
+            _ => return,
+        }
+        if members.len() < 2 {
+            return;
+        }
+        for (i, (range, ty)) in members.iter().enumerate() {
+            if !matches!(ty, Type::Literal(_)) {
+                continue;
+            }
+            let msg = if members[..i].iter().any(|(_, other)| other == ty) {
+                format!(
+                    "`{}` appears more than once in this union, consider removing the duplicate",
+                    self.for_display(ty.clone())
+                )
+            } else if let Some((_, broader)) = members
+                .iter()
+                .find(|(_, other)| other != ty && !other.is_any() && self.is_subset_eq(ty, other))
+            {
+                format!(
+                    "`{}` is redundant because `{}` already includes it, consider removing it",
+                    self.for_display(ty.clone()),
+                    self.for_display(broader.clone())
+                )
+            } else {
+                continue;
+            };
+            self.error(errors, *range, ErrorKind::RedundantLiteral, None, msg);
+        }
+    }
+
+    /// The members of a union written with `|`, `Union[...]` or `Literal[...]`, without any
+    /// simplification.
+    fn collect_union_members(
+        &self,
+        x: &Expr,
+        errors: &ErrorCollector,
+        members: &mut Vec<(TextRange, Type)>,
+    ) {
+        match x {
+            Expr::BinOp(ExprBinOp {
+                op: Operator::BitOr,
+                left,
+                right,
+                ..
+            }) => {
+                self.collect_union_members(left, errors, members);
+                self.collect_union_members(right, errors, members);
+            }
+            Expr::Subscript(s) => {
+                let value_ty = self.expr_infer(&s.value, errors);
+                self.collect_subscript_members(x, s, value_ty, errors, members);
+            }
+            _ => members.push((
+                x.range(),
+                self.expr_untype(x, TypeFormContext::TypeArgument, errors),
+            )),
+        }
+    }
+
+    /// Like `collect_union_members`, for the subscript `x` (which is `s`) whose value has
+    /// type `value_ty`.
+    fn collect_subscript_members(
+        &self,
+        x: &Expr,
+        s: &ExprSubscript,
+        value_ty: Type,
+        errors: &ErrorCollector,
+        members: &mut Vec<(TextRange, Type)>,
+    ) {
+        match value_ty {
+            Type::Type(box Type::SpecialForm(SpecialForm::Union)) => {
+                for arg in Ast::unpack_slice(&s.slice) {
+                    self.collect_union_members(arg, errors, members);
+                }
+            }
+            Type::Type(box Type::SpecialForm(SpecialForm::Literal)) => {
+                for arg in Ast::unpack_slice(&s.slice) {
+                    let mut literals = Vec::new();
+                    self.apply_literal(arg, errors, &mut literals);
+                    for literal in literals {
+                        self.map_over_union(&literal, |ty| members.push((arg.range(), ty.clone())));
+                    }
+                }
+            }
+            _ => members.push((
+                x.range(),
+                self.expr_untype(x, TypeFormContext::TypeArgument, errors),
+            )),
+        }
+    }
+
     fn expr_annotation(
         &self,
         x: &Expr,
@@ -465,6 +655,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             }
             _ => {
                 let ann_ty = self.expr_untype(x, type_form_context, errors);
+                self.check_redundant_literals(x, &ann_ty, errors);
                 if let Type::SpecialForm(special_form) = ann_ty
                     && !special_form.is_valid_unparameterized_annotation(type_form_context)
                 {
@@ -507,6 +698,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             _ => {
                 let ty = self
                     .unwrap_iterable(iterable)
+                    .or_else(|| self.check_dunder_iter_result(iterable, range, errors, &context))
                     .or_else(|| {
                         let int_ty = self.stdlib.int().clone().to_type();
                         let arg = CallArg::Type(&int_ty, range);
@@ -534,6 +726,50 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
     }
 
+    /// If `iterable` has an `__iter__` method that doesn't return an iterator, report that
+    /// specifically rather than the generic "not iterable" error, and return the error type.
+    fn check_dunder_iter_result(
+        &self,
+        iterable: &Type,
+        range: TextRange,
+        errors: &ErrorCollector,
+        context: &dyn Fn() -> ErrorContext,
+    ) -> Option<Type> {
+        let iterator = self.call_magic_dunder_method(
+            iterable,
+            &dunder::ITER,
+            range,
+            &[],
+            &[],
+            &self.error_swallower(),
+            None,
+        )?;
+        let has_next = self
+            .type_of_magic_dunder_attr(
+                &iterator,
+                &dunder::NEXT,
+                range,
+                &self.error_swallower(),
+                None,
+                "Expr::iterate",
+            )
+            .is_some();
+        if has_next {
+            None
+        } else {
+            Some(self.error(
+                errors,
+                range,
+                ErrorKind::NotIterable,
+                Some(context),
+                format!(
+                    "`__iter__` must return an iterator (an object with `__next__`), got `{}`",
+                    self.for_display(iterator)
+                ),
+            ))
+        }
+    }
+
     /// Given a type, determine the async iteration type; this is the type
     /// of `x` if we were to loop using `async for x in iterable`.
     pub fn async_iterate(
@@ -549,13 +785,29 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             _ => {
                 let context = || ErrorContext::AsyncIteration(self.for_display(iterable.clone()));
                 let ty = self.unwrap_async_iterable(iterable).unwrap_or_else(|| {
-                    self.error(
-                        errors,
-                        range,
-                        ErrorKind::NotIterable,
-                        None,
-                        context().format(),
-                    )
+                    if let Some(ty) = self.unwrap_iterable(iterable) {
+                        // A common mistake is to write `async for` over an ordinary iterable.
+                        // Point that out, and carry on with the element type to avoid follow-on errors.
+                        self.error(
+                            errors,
+                            range,
+                            ErrorKind::NotIterable,
+                            None,
+                            format!(
+                                "{}, it is a sync iterable, use a regular `for` instead",
+                                context().format()
+                            ),
+                        );
+                        ty
+                    } else {
+                        self.error(
+                            errors,
+                            range,
+                            ErrorKind::NotIterable,
+                            None,
+                            context().format(),
+                        )
+                    }
                 });
                 vec![Iterable::OfType(ty)]
             }
@@ -786,6 +1038,69 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         Forallable::TypeAlias(ta).forall(self.type_params(range, tparams, errors))
     }
 
+    /// Given the type of a type alias, return the number of type parameters it takes, along with
+    /// how many of them are required (i.e. don't have a default). Aliases that aren't generic
+    /// take no parameters. Returns `None` if the type isn't a type alias.
+    #[cfg_attr(not(test), expect(dead_code))] // For rendering aliases in the IDE
+    pub fn type_alias_arity(&self, ta: &Type) -> Option<(usize, usize)> {
+        match ta {
+            Type::Forall(box Forall {
+                tparams,
+                body: Forallable::TypeAlias(_),
+            }) => {
+                let required = tparams.iter().filter(|p| p.default().is_none()).count();
+                Some((tparams.len(), required))
+            }
+            Type::TypeAlias(_) => Some((0, 0)),
+            _ => None,
+        }
+    }
+
+    /// The most specific type that both `a` and `b` are assignable to. If one is assignable
+    /// to the other, that's the answer. For class instances (including literals, which are
+    /// treated as their class), we walk the MRO of `a` and return the first ancestor that
+    /// `b` is also assignable to. When there is no meaningful common base - the types aren't
+    /// both classes, or the only shared ancestor is `object` - we fall back to their union.
+    /// Types that still contain unsolved variables also give their union, since comparing them
+    /// would pin the variables.
+    pub fn common_supertype(&self, a: &Type, b: &Type) -> Type {
+        let a = self.solver().expand(a.clone());
+        let b = self.solver().expand(b.clone());
+        if a.any(|t| matches!(t, Type::Var(_))) || b.any(|t| matches!(t, Type::Var(_))) {
+            return self.unions(vec![a, b]);
+        }
+        if self.is_subset_eq(&a, &b) {
+            return b;
+        }
+        if self.is_subset_eq(&b, &a) {
+            return a;
+        }
+        let as_class_type = |t: &Type| match t {
+            Type::ClassType(cls) => Some(cls.clone()),
+            Type::Literal(lit) => Some(lit.general_class_type(self.stdlib).clone()),
+            _ => None,
+        };
+        if let Some(a_cls) = as_class_type(&a)
+            && as_class_type(&b).is_some()
+        {
+            let metadata = self.get_metadata_for_class(a_cls.class_object());
+            let mro = iter::once(a_cls.class_object()).chain(
+                metadata
+                    .ancestors_no_object()
+                    .iter()
+                    .map(|c| c.class_object()),
+            );
+            for ancestor in mro {
+                if let Some(sup) = self.as_superclass(&a_cls, ancestor).map(|c| c.to_type())
+                    && self.is_subset_eq(&b, &sup)
+                {
+                    return sup;
+                }
+            }
+        }
+        self.unions(vec![a, b])
+    }
+
     fn context_value_enter(
         &self,
         context_manager_type: &Type,
@@ -1049,6 +1364,34 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         Arc::new(type_info)
     }
 
+    /// Get the type of an assignment target, alongside the type it was declared with.
+    /// The first element is the type currently in effect, which may have been narrowed.
+    /// The second is the type of the target's annotation, if it has one. Anything assigned
+    /// to the target is checked against the declared type, not the narrowed one.
+    ///
+    /// The declared type is recovered from the binding's annotation on demand rather than stored
+    /// in `TypeInfo`, which every key's answer holds and which is kept to a fixed size.
+    #[cfg_attr(not(test), expect(dead_code))] // Exposed for tooling that explains narrowing to the user
+    pub fn widen_to_declared(&self, idx: Idx<Key>) -> (Type, Option<Type>) {
+        let narrowed = self.get_idx(idx).arc_clone_ty();
+        (narrowed, self.declared_type(idx))
+    }
+
+    fn declared_type(&self, idx: Idx<Key>) -> Option<Type> {
+        let annot = match self.bindings().get(idx) {
+            Binding::Forward(fwd) => return self.declared_type(*fwd),
+            Binding::NameAssign(_, Some((_, annot)), _)
+            | Binding::AnnotatedType(annot, _)
+            | Binding::Expr(Some(annot), _)
+            | Binding::IterableValue(Some(annot), _, _)
+            | Binding::ContextValue(Some(annot), _, _, _)
+            | Binding::AugAssign(Some(annot), _)
+            | Binding::FunctionParameter(Either::Left(annot)) => *annot,
+            _ => return None,
+        };
+        self.get_idx(annot).ty(self.stdlib)
+    }
+
     pub fn expand_type_mut(&self, ty: &mut Type) {
         // Replace any solved recursive variables with their answers.
         // We call self.unions() to simplify cases like
@@ -1073,21 +1416,54 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 // test that fails if we do not discard the errors from expr_infer() here.
                 let ty = self.expr_infer(x, &self.error_swallower());
                 self.check_dunder_bool_is_callable(&ty, *range, errors);
+                self.check_constant_condition(x, &ty, errors);
             }
-            BindingExpect::Delete(box x) => match x {
-                Expr::Name(_) => {
+            BindingExpect::UnreachableBranch(idx, box x, body, later_clauses) => {
+                let ty = self.get_idx(*idx);
+                self.check_unreachable_branch(x, ty.ty(), *body, *later_clauses, errors);
+            }
+            BindingExpect::Delete(ann, box x) => match x {
+                Expr::Name(name) => {
+                    if let Some(ann) = ann
+                        && self.get_idx(*ann).annotation.is_final()
+                    {
+                        self.error(
+                            errors,
+                            x.range(),
+                            ErrorKind::DeleteError,
+                            None,
+                            format!("Cannot delete Final variable `{}`", name.id),
+                        );
+                    }
                     self.expr_infer(x, errors);
                 }
                 Expr::Attribute(attr) => {
                     let base = self.expr_infer(&attr.value, errors);
-                    self.check_attr_delete(
-                        &base,
-                        &attr.attr.id,
-                        attr.range,
-                        errors,
-                        None,
-                        "Answers::solve_expectation::Delete",
-                    );
+                    let cls = match &base {
+                        Type::ClassType(cls) | Type::SelfType(cls) => Some(cls.class_object()),
+                        Type::ClassDef(cls) => Some(cls),
+                        _ => None,
+                    };
+                    if let Some(cls) = cls
+                        && self.is_final_class_member(cls, &attr.attr.id)
+                    {
+                        self.error(
+                            errors,
+                            attr.range,
+                            ErrorKind::DeleteError,
+                            None,
+                            format!("Cannot delete Final attribute `{}`", attr.attr.id),
+                        );
+                    } else {
+                        self.check_attr_delete(
+                            &base,
+                            &attr.attr.id,
+                            attr.range,
+                            errors,
+                            None,
+                            "Answers::solve_expectation::Delete",
+                        );
+                    }
                 }
                 Expr::Subscript(x) => {
                     let base = self.expr_infer(&x.value, errors);
@@ -1229,10 +1605,145 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                     );
                 }
             }
+            BindingExpect::MatchesStub(x, name) => {
+                self.check_matches_stub(*x, name, errors);
+            }
+            BindingExpect::ReusedContextManager(x, first) => {
+                let context_manager = self.get_idx(*x);
+                if let Type::ClassType(cls) = context_manager.ty()
+                    && (cls.has_qname("contextlib", "_GeneratorContextManager")
+                        || cls.has_qname("contextlib", "_AsyncGeneratorContextManager"))
+                {
+                    self.error(
+                        errors,
+                        self.bindings().idx_to_key(*x).range(),
+                        ErrorKind::ReusedContextManager,
+                        None,
+                        format!(
+                            "Context manager was already entered at {}, generator-based context managers can only be entered once",
+                            self.module_info().source_range(*first),
+                        ),
+                    );
+                }
+            }
         }
         Arc::new(EmptyAnswer)
     }
 
+    /// If this module has a stub that declares `name` itself, check that the function or class
+    /// bound to `x` is consistent with the stub's declaration. Classes are compared member by
+    /// member, for each member declared in the stub's class body.
+    fn check_matches_stub(&self, x: Idx<Key>, name: &Name, errors: &ErrorCollector) {
+        if !self.bindings().is_error_enabled(ErrorKind::StubMismatch) {
+            return;
+        }
+        // Imports of this module resolve to the stub, if there is one.
+        let Some(stub) = self
+            .exports
+            .get(self.module_info().name())
+            .ok()
+            .filter(|x| x.path().is_interface())
+        else {
+            return;
+        };
+        if !matches!(
+            stub.exports(self.exports).get(name),
+            Some(ExportLocation::ThisModule(_))
+        ) {
+            return;
+        }
+        let impl_ty = self.get_idx(x);
+        let stub_ty = self.get_from_module(
+            self.module_info().name(),
+            Some(stub.path()),
+            &KeyExport(name.clone()),
+        );
+        let range = self.bindings().idx_to_key(x).range();
+        let stub_path = stub.path().as_path();
+        match impl_ty.ty() {
+            Type::Function(_) | Type::Overload(_) | Type::Forall(_) => {
+                self.check_stub_member(name, impl_ty.ty(), &stub_ty, stub_path, range, errors);
+            }
+            Type::ClassDef(cls) => {
+                let Type::ClassDef(stub_cls) = &*stub_ty else {
+                    return;
+                };
+                for field in stub_cls.fields() {
+                    let Some(stub_field_ty) = self.class_member_type(stub_cls, field) else {
+                        continue;
+                    };
+                    let member = format!("{name}.{field}");
+                    match self.class_member_type(cls, field) {
+                        Some(impl_field_ty) => {
+                            // The stub's class is a different class from ours, so refer to ours
+                            // in its members, e.g. in the type of `self`.
+                            let stub_field_ty = stub_field_ty.transform(&mut |t| match t {
+                                Type::ClassDef(c) if c == stub_cls => *c = cls.dupe(),
+                                Type::ClassType(c) | Type::SelfType(c)
+                                    if c.class_object() == stub_cls
+                                        && c.targs().as_slice().len() == cls.tparams().len() =>
+                                {
+                                    *c = ClassType::new(cls.dupe(), c.targs().clone())
+                                }
+                                _ => {}
+                            });
+                            self.check_stub_member(
+                                &member,
+                                &impl_field_ty,
+                                &stub_field_ty,
+                                stub_path,
+                                range,
+                                errors,
+                            );
+                        }
+                        None => {
+                            self.error(
+                                errors,
+                                range,
+                                ErrorKind::StubMismatch,
+                                None,
+                                format!(
+                                    "`{member}` is declared in `{}` but not defined",
+                                    stub_path.display()
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn check_stub_member(
+        &self,
+        name: &str,
+        impl_ty: &Type,
+        stub_ty: &Type,
+        stub_path: &Path,
+        range: TextRange,
+        errors: &ErrorCollector,
+    ) {
+        if !self.is_subset_eq(impl_ty, stub_ty) {
+            let impl_ty = self.for_display(impl_ty.clone());
+            let stub_ty = self.for_display(stub_ty.clone());
+            let ctx = TypeDisplayContext::new(&[&impl_ty, &stub_ty]);
+            self.error(
+                errors,
+                range,
+                ErrorKind::StubMismatch,
+                None,
+                format!(
+                    "`{}` has type `{}`, which is not consistent with its declaration `{}` in `{}`",
+                    name,
+                    ctx.display(&impl_ty),
+                    ctx.display(&stub_ty),
+                    stub_path.display(),
+                ),
+            );
+        }
+    }
+
     pub fn solve_class(
         &self,
         cls: &BindingClass,
@@ -1889,9 +2400,23 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                     ),
                 }
             }
-            Binding::PatternMatchClassKeyword(_, attr, key) => {
-                // TODO: check that value matches class
+            Binding::PatternMatchClassKeyword(cls, attr, key) => {
                 // TODO: check against duplicate keys (optional)
+                // Catch keywords that can never match, e.g. typos, by looking at the class itself.
+                // Errors in the class expression are reported where the pattern is narrowed.
+                if let Type::ClassDef(cls) = self.expr_infer(cls, &self.error_swallower())
+                    && self
+                        .try_lookup_attr_no_union(&self.promote_silently(&cls), &attr.id)
+                        .is_none()
+                {
+                    return self.error(
+                        errors,
+                        attr.range,
+                        ErrorKind::MissingAttribute,
+                        None,
+                        format!("`{}` has no attribute `{}`", cls.name(), attr.id),
+                    );
+                }
                 let binding = self.get_idx(*key);
                 self.attr_infer(&binding, &attr.id, attr.range, errors, None)
                     .into_ty()
@@ -2041,7 +2566,9 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                     }
                     ty
                 } else {
-                    let returns = x.returns.iter().map(|k| self.get_idx(*k).arc_clone_ty());
+                    let returns = x.returns.map(|k| self.get_idx(*k).arc_clone_ty());
+                    self.check_inconsistent_returns(&returns, x.range, errors);
+                    let returns = returns.into_iter();
                     // TODO: It should always be a no-op to include a `Type::Never` in unions, but
                     // `simple::test_solver_variables` fails if we do, because `solver::unions` does
                     // `is_subset_eq` to force free variables, causing them to be equated to
@@ -2153,7 +2680,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                     Type::None
                 }
             }
-            Binding::ExceptionHandler(box ann, is_star) => {
+            Binding::ExceptionHandler(box ann, is_star, reraises) => {
                 let base_exception_type = self.stdlib.base_exception().clone().to_type();
                 let base_exception_group_any_type = if *is_star {
                     // Only query for `BaseExceptionGroup` if we see an `except*` handler (which
@@ -2182,6 +2709,18 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                     self.check_type(&base_exception_type, &exception, range, errors, &|| {
                         TypeCheckContext::of_kind(TypeCheckKind::ExceptionClass)
                     });
+                    if !*reraises
+                        && !exception.is_any()
+                        && self.is_subset_eq(&base_exception_type, &exception)
+                    {
+                        self.error(
+                            errors,
+                            range,
+                            ErrorKind::BroadExcept,
+                            None,
+                            "Catching `BaseException` also catches `KeyboardInterrupt` and `SystemExit`, use `except Exception` instead".to_owned(),
+                        );
+                    }
                     if let Some(base_exception_group_any_type) =
                         base_exception_group_any_type.as_ref()
                         && !exception.is_any()
@@ -2654,6 +3193,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
             // A generic type alias with no type arguments is OK if all the type params have defaults
             let targs = self.check_and_create_targs(
                 &forall.body.name(),
+                matches!(forall.body, Forallable::TypeAlias(_)),
                 &forall.tparams,
                 Vec::new(),
                 range,
@@ -2711,6 +3251,34 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
     }
 
+    /// Replace every type alias inside `ty` with the type it stands for, all the way down.
+    /// Intended for display, so never reports errors. An alias that refers back to itself
+    /// is left as-is at the point where it recurs.
+    #[cfg_attr(not(test), expect(dead_code))] // Will back a "show expanded type" hover
+    pub fn expand_aliases(&self, mut ty: Type) -> Type {
+        self.expand_aliases_mut(&mut ty, &mut Vec::new());
+        ty
+    }
+
+    fn expand_aliases_mut(&self, ty: &mut Type, expanding: &mut Vec<Name>) {
+        match ty {
+            Type::TypeAlias(ta) if !expanding.contains(&ta.name) => {
+                expanding.push((*ta.name).clone());
+                *ty = match ta.as_type() {
+                    Type::Type(box t) => t,
+                    t => t,
+                };
+                self.expand_aliases_mut(ty, expanding);
+                expanding.pop();
+            }
+            Type::Var(v) if let Some(_guard) = self.recurser.recurse(*v) => {
+                *ty = self.solver().force_var(*v);
+                self.expand_aliases_mut(ty, expanding);
+            }
+            _ => ty.recurse_mut(&mut |x| self.expand_aliases_mut(x, expanding)),
+        }
+    }
+
     pub fn validate_type_form(
         &self,
         ty: Type,
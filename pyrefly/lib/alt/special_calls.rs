@@ -21,8 +21,11 @@ use crate::alt::answers::LookupAnswer;
 use crate::alt::solve::TypeFormContext;
 use crate::error::collector::ErrorCollector;
 use crate::error::kind::ErrorKind;
+use crate::types::callable::FunctionKind;
 use crate::types::callable::unexpected_keyword;
 use crate::types::special_form::SpecialForm;
+use crate::types::types::AnyStyle;
+use crate::types::types::CalleeKind;
 use crate::types::types::Type;
 use crate::util::visit::VisitMut;
 
@@ -228,6 +231,30 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                 "`typing.cast` missing required argument `val`".to_owned(),
             );
         }
+        if matches!(ret, Type::Any(style) if style != AnyStyle::Error) {
+            self.error(
+                errors,
+                range,
+                ErrorKind::RedundantCast,
+                None,
+                "Cast to `Any` is unnecessary, use the value directly".to_owned(),
+            );
+        }
+        if let Some(Expr::Call(inner)) = val
+            && matches!(
+                self.expr_infer(&inner.func, errors).callee_kind(),
+                Some(CalleeKind::Function(FunctionKind::Cast))
+            )
+        {
+            self.error(
+                errors,
+                inner.range,
+                ErrorKind::RedundantCast,
+                None,
+                "Cast of the result of another `cast`, only the outer cast has any effect"
+                    .to_owned(),
+            );
+        }
         ret
     }
 }
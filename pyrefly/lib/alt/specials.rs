@@ -10,6 +10,7 @@ use std::slice;
 use ruff_python_ast::Expr;
 use ruff_python_ast::ExprAttribute;
 use ruff_python_ast::ExprList;
+use ruff_python_ast::ExprNumberLiteral;
 use ruff_python_ast::ExprUnaryOp;
 use ruff_python_ast::Number;
 use ruff_python_ast::UnaryOp;
@@ -168,7 +169,7 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
     }
 
-    fn apply_literal(&self, x: &Expr, errors: &ErrorCollector, literals: &mut Vec<Type>) {
+    pub fn apply_literal(&self, x: &Expr, errors: &ErrorCollector, literals: &mut Vec<Type>) {
         match x {
             Expr::UnaryOp(ExprUnaryOp {
                 op: UnaryOp::UAdd,
@@ -255,6 +256,26 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
                     }
                 });
             }
+            // Integers were handled above, so these are floats or complex numbers.
+            Expr::NumberLiteral(ExprNumberLiteral { value, .. })
+            | Expr::UnaryOp(ExprUnaryOp {
+                op: UnaryOp::UAdd | UnaryOp::USub,
+                operand: box Expr::NumberLiteral(ExprNumberLiteral { value, .. }),
+                ..
+            }) => {
+                let kind = if matches!(value, Number::Complex { .. }) {
+                    "complex"
+                } else {
+                    "float"
+                };
+                errors.add(
+                    x.range(),
+                    format!("`{kind}` values are not allowed in `Literal`"),
+                    ErrorKind::InvalidLiteral,
+                    None,
+                );
+                literals.push(Type::any_error())
+            }
             _ => {
                 errors.add(
                     x.range(),
@@ -40,10 +40,16 @@ pub struct ClassMetadata {
     enum_metadata: Option<EnumMetadata>,
     protocol_metadata: Option<ProtocolMetadata>,
     dataclass_metadata: Option<DataclassMetadata>,
+    /// The dataclass keyword defaults from a `@dataclass_transform` on this class, one of its
+    /// bases, or its metaclass. Classes that pick this up from a base or metaclass are dataclasses.
+    dataclass_transform_metadata: Option<BoolKeywords>,
     bases_with_metadata: Vec<(ClassType, Arc<ClassMetadata>)>,
     has_base_any: bool,
     is_new_type: bool,
     is_final: bool,
+    /// Methods decorated with `@abstractmethod`, in this class or an ancestor, that nothing
+    /// earlier in the MRO overrides.
+    abstract_methods: SmallSet<Name>,
     /// Is it possible for this class to have type parameters that we don't know about?
     /// This can happen if, e.g., a class inherits from Any.
     has_unknown_tparams: bool,
@@ -74,6 +80,7 @@ impl ClassMetadata {
         enum_metadata: Option<EnumMetadata>,
         protocol_metadata: Option<ProtocolMetadata>,
         dataclass_metadata: Option<DataclassMetadata>,
+        dataclass_transform_metadata: Option<BoolKeywords>,
         has_base_any: bool,
         is_new_type: bool,
         is_final: bool,
@@ -81,6 +88,7 @@ impl ClassMetadata {
         errors: &ErrorCollector,
     ) -> ClassMetadata {
         let mro = Mro::new(cls, &bases_with_metadata, errors);
+        let abstract_methods = Self::calculate_abstract_methods(cls, &mro);
         ClassMetadata {
             mro,
             metaclass: Metaclass(metaclass),
@@ -90,14 +98,36 @@ impl ClassMetadata {
             enum_metadata,
             protocol_metadata,
             dataclass_metadata,
+            dataclass_transform_metadata,
             bases_with_metadata,
             has_base_any,
             is_new_type,
             is_final,
+            abstract_methods,
             has_unknown_tparams,
         }
     }
 
+    fn calculate_abstract_methods(cls: &Class, mro: &Mro) -> SmallSet<Name> {
+        let classes = || {
+            iter::once(cls).chain(
+                mro.ancestors_no_object()
+                    .iter()
+                    .map(|ancestor| ancestor.class_object()),
+            )
+        };
+        classes()
+            .flat_map(|c| c.fields().filter(|name| c.is_field_abstract_method(name)))
+            .filter(|name| {
+                // The first class in the MRO to define the name decides whether it's abstract.
+                classes()
+                    .find(|c| c.contains(name))
+                    .is_some_and(|c| c.is_field_abstract_method(name))
+            })
+            .cloned()
+            .collect()
+    }
+
     pub fn recursive() -> Self {
         ClassMetadata {
             mro: Mro::Cyclic,
@@ -108,10 +138,12 @@ impl ClassMetadata {
             enum_metadata: None,
             protocol_metadata: None,
             dataclass_metadata: None,
+            dataclass_transform_metadata: None,
             bases_with_metadata: Vec::new(),
             has_base_any: false,
             is_new_type: false,
             is_final: false,
+            abstract_methods: SmallSet::new(),
             has_unknown_tparams: false,
         }
     }
@@ -133,6 +165,10 @@ impl ClassMetadata {
         self.is_final
     }
 
+    pub fn abstract_methods(&self) -> &SmallSet<Name> {
+        &self.abstract_methods
+    }
+
     pub fn has_base_any(&self) -> bool {
         self.has_base_any
     }
@@ -177,6 +213,10 @@ impl ClassMetadata {
         self.dataclass_metadata.as_ref()
     }
 
+    pub fn dataclass_transform_metadata(&self) -> Option<&BoolKeywords> {
+        self.dataclass_transform_metadata.as_ref()
+    }
+
     pub fn ancestors<'a>(&'a self, stdlib: &'a Stdlib) -> impl Iterator<Item = &'a ClassType> {
         self.ancestors_no_object()
             .iter()
@@ -99,6 +99,18 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
     }
 
+    /// The type produced by `await`ing a value of type `ty`, or `None` if it isn't awaitable.
+    /// This covers `Coroutine`, `Awaitable`, and any class defining a suitable `__await__`.
+    pub fn awaited_type(&self, ty: &Type) -> Option<Type> {
+        self.unwrap_awaitable(ty)
+            .map(|awaited| self.solver().deep_force(awaited))
+    }
+
+    #[cfg_attr(not(test), expect(dead_code))] // Only needed by async analysis tooling so far
+    pub fn is_awaitable(&self, ty: &Type) -> bool {
+        self.awaited_type(ty).is_some()
+    }
+
     pub fn unwrap_generator(&self, ty: &Type) -> Option<(Type, Type, Type)> {
         let yield_ty = self.fresh_var();
         let send_ty = self.fresh_var();
@@ -117,6 +129,18 @@ impl<'a, Ans: LookupAnswer> AnswersSolver<'a, Ans> {
         }
     }
 
+    /// Peel one `type[...]` layer off `ty`, so `type[int]` gives `int`. Vars are expanded and
+    /// type aliases are looked through (as values, so `A = int` gives `int`) first.
+    /// Returns `None` if `ty` isn't a `type[...]`.
+    #[cfg_attr(not(test), expect(dead_code))] // For tooling that distinguishes class objects from instances
+    pub fn unwrap_type_type(&self, ty: &Type) -> Option<Type> {
+        match self.solver().expand(ty.clone()) {
+            Type::Type(box t) => Some(t),
+            Type::TypeAlias(ta) => self.unwrap_type_type(&ta.as_value(self.stdlib)),
+            _ => None,
+        }
+    }
+
     pub fn unwrap_iterable(&self, ty: &Type) -> Option<Type> {
         let iter_ty = self.fresh_var();
         let iterable_ty = self.stdlib.iterable(iter_ty.to_type()).to_type();
@@ -285,10 +285,20 @@ pub enum BindingExpect {
         existing: Idx<KeyAnnotation>,
         name: Name,
     },
-    /// `del` statement
-    Delete(Box<Expr>),
+    /// `del` statement, with the annotation of the deleted name if there is one.
+    Delete(Option<Idx<KeyAnnotation>>, Box<Expr>),
     /// Expression used in a boolean context (`bool()`, `if`, or `while`)
     Bool(Box<Expr>, TextRange),
+    /// The condition of an `if` or `elif` branch, along with the key of its inferred type, the
+    /// range of the branch body and of the clauses after it, which are unreachable if the
+    /// condition is always false or always true.
+    UnreachableBranch(Idx<Key>, Box<Expr>, TextRange, Option<TextRange>),
+    /// A name used as a context manager after it has already been entered by an earlier
+    /// `with` statement. The key is the context expression, the range is the earlier use.
+    ReusedContextManager(Idx<Key>, TextRange),
+    /// A name exported from an implementation file, which should be consistent with the
+    /// declaration of the same name in the module's stub, if it has one.
+    MatchesStub(Idx<Key>, Name),
 }
 
 impl DisplayWith<Bindings> for BindingExpect {
@@ -301,7 +311,10 @@ impl DisplayWith<Bindings> for BindingExpect {
             Self::Bool(box x, ..) => {
                 write!(f, "check bool expr {}", m.display(x))
             }
-            Self::Delete(box x) => {
+            Self::UnreachableBranch(_, box x, ..) => {
+                write!(f, "check reachability of branches on {}", m.display(x))
+            }
+            Self::Delete(_, box x) => {
                 write!(f, "del {}", m.display(x))
             }
             Self::UnpackedLength(x, range, expect) => {
@@ -334,6 +347,17 @@ impl DisplayWith<Bindings> for BindingExpect {
                 ctx.display(*existing),
                 name
             ),
+            Self::ReusedContextManager(x, first) => {
+                write!(f, "reused context manager {} {:?}", ctx.display(*x), first)
+            }
+            Self::MatchesStub(x, name) => {
+                write!(
+                    f,
+                    "{} matches stub declaration of {}",
+                    ctx.display(*x),
+                    name
+                )
+            }
         }
     }
 }
@@ -634,6 +658,8 @@ pub struct ReturnExplicit {
 
 #[derive(Clone, Debug)]
 pub struct ReturnType {
+    /// The range of the function name, used for diagnostics about the function as a whole.
+    pub range: TextRange,
     /// The annotation for the return type.
     pub annot: Option<(TextRange, Idx<KeyAnnotation>)>,
     /// The returns from the function.
@@ -788,8 +814,9 @@ pub enum Binding {
     /// Positional patterns index into __match_args__, and keyword patterns match an attribute name.
     PatternMatchClassPositional(Box<Expr>, usize, Idx<Key>, TextRange),
     PatternMatchClassKeyword(Box<Expr>, Identifier, Idx<Key>),
-    /// Binding for an `except` (if the boolean flag is false) or `except*` (if the boolean flag is true) clause
-    ExceptionHandler(Box<Expr>, bool),
+    /// Binding for an `except` (if the first boolean flag is false) or `except*` (if the first
+    /// boolean flag is true) clause. The second flag is whether the handler re-raises the exception.
+    ExceptionHandler(Box<Expr>, bool, bool),
     /// Binding for an `@decorator` decoration on a function or class
     Decorator(Expr),
     /// Binding for a lambda parameter.
@@ -846,8 +873,8 @@ impl DisplayWith<Bindings> for Binding {
             Self::IterableValue(Some(k), x, IsAsync::Sync) => {
                 write!(f, "iter {}: {}", ctx.display(*k), m.display(x))
             }
-            Self::ExceptionHandler(box x, true) => write!(f, "except* {}", m.display(x)),
-            Self::ExceptionHandler(box x, false) => write!(f, "except {}", m.display(x)),
+            Self::ExceptionHandler(box x, true, _) => write!(f, "except* {}", m.display(x)),
+            Self::ExceptionHandler(box x, false, _) => write!(f, "except {}", m.display(x)),
             Self::ContextValue(_ann, x, _, kind) => {
                 let name = match kind {
                     IsAsync::Sync => "context",
@@ -39,6 +39,7 @@ use vec1::Vec1;
 use crate::binding::binding::AnnotationTarget;
 use crate::binding::binding::Binding;
 use crate::binding::binding::BindingAnnotation;
+use crate::binding::binding::BindingExpect;
 use crate::binding::binding::BindingExport;
 use crate::binding::binding::BindingLegacyTypeParam;
 use crate::binding::binding::BindingYield;
@@ -47,6 +48,7 @@ use crate::binding::binding::ExprOrBinding;
 use crate::binding::binding::Key;
 use crate::binding::binding::KeyAnnotation;
 use crate::binding::binding::KeyClass;
+use crate::binding::binding::KeyExpect;
 use crate::binding::binding::KeyExport;
 use crate::binding::binding::KeyFunction;
 use crate::binding::binding::KeyLegacyTypeParam;
@@ -67,6 +69,7 @@ use crate::binding::scope::ScopeTrace;
 use crate::binding::scope::Scopes;
 use crate::binding::table::TableKeyed;
 use crate::config::base::UntypedDefBehavior;
+use crate::config::error::ErrorDisplayConfig;
 use crate::error::collector::ErrorCollector;
 use crate::error::kind::ErrorKind;
 use crate::export::exports::Exports;
@@ -107,6 +110,8 @@ struct BindingsInner {
     module_info: ModuleInfo,
     table: BindingTable,
     scope_trace: Option<ScopeTrace>,
+    untyped_def_behavior: UntypedDefBehavior,
+    error_config: ErrorDisplayConfig,
 }
 
 impl Display for Bindings {
@@ -173,6 +178,16 @@ impl Bindings {
         &self.0.module_info
     }
 
+    pub fn untyped_def_behavior(&self) -> UntypedDefBehavior {
+        self.0.untyped_def_behavior
+    }
+
+    /// Whether errors of the given kind are displayed for this module, so that
+    /// expensive opt-in checks can be skipped when nobody will see the result.
+    pub fn is_error_enabled(&self, kind: ErrorKind) -> bool {
+        self.0.error_config.is_enabled(kind)
+    }
+
     pub fn available_definitions(&self, position: TextSize) -> SmallSet<Idx<Key>> {
         if let Some(trace) = &self.0.scope_trace {
             trace.available_definitions(&self.0.table, position)
@@ -300,6 +315,7 @@ impl Bindings {
         uniques: &UniqueFactory,
         enable_trace: bool,
         untyped_def_behavior: UntypedDefBehavior,
+        error_config: ErrorDisplayConfig,
     ) -> Self {
         let mut builder = BindingsBuilder {
             module_info: module_info.dupe(),
@@ -355,12 +371,28 @@ impl Bindings {
         let scope_trace = builder.scopes.finish();
         let last_scope = scope_trace.toplevel_scope();
         let exported = exports.exports(lookup);
+        // If imports of this module resolve to a stub, then the stub is the interface users see,
+        // and the functions and classes we export should be consistent with it. Whether there is
+        // such a stub is only known once other modules are available, so the solver decides.
+        let check_stub = !module_info.path().is_interface();
         for (k, static_info) in last_scope.stat.0.iter_hashed() {
             if static_info.is_nonlocal() || static_info.is_global() {
                 // Nonlocal and global don't do anything outside a function
                 continue;
             }
             let info = last_scope.flow.info.get_hashed(k);
+            if check_stub
+                && let Some(FlowInfo { key, style, .. }) = info
+                && (matches!(style, FlowStyle::FunctionDef(..))
+                    || matches!(builder.table.types.1.get(*key), Some(Binding::ClassDef(..))))
+                && exported.contains_key_hashed(k)
+            {
+                let range = builder.table.types.0.idx_to_key(*key).range();
+                builder.table.insert(
+                    KeyExpect(range),
+                    BindingExpect::MatchesStub(*key, k.into_key().clone()),
+                );
+            }
             let binding = match info {
                 Some(FlowInfo { key, .. }) => {
                     if let Some(ann) = static_info.annot {
@@ -395,6 +427,8 @@ impl Bindings {
             } else {
                 None
             },
+            untyped_def_behavior,
+            error_config,
         }))
     }
 }
@@ -475,8 +509,10 @@ pub enum LookupKind {
 }
 
 pub enum MutableCaptureLookupError {
-    /// We can't find the name at all
-    NotFound,
+    /// We can't find the name of a `nonlocal` declaration in any scope
+    NonlocalNotFound,
+    /// We can't find the name of a `global` declaration in any scope
+    GlobalNotFound,
     /// We expected the name to be in an enclosing, non-global scope, but it's not
     NonlocalScope,
     /// This variable was assigned before the nonlocal declaration
@@ -490,7 +526,12 @@ pub enum MutableCaptureLookupError {
 impl MutableCaptureLookupError {
     pub fn message(&self, name: &Identifier) -> String {
         match self {
-            Self::NotFound => format!("Could not find name `{name}`"),
+            Self::NonlocalNotFound => {
+                format!("No binding for `nonlocal {name}` found in an enclosing scope")
+            }
+            Self::GlobalNotFound => {
+                format!("No binding for `global {name}` found at module level")
+            }
             Self::NonlocalScope => {
                 format!("Found `{name}`, but it was not in a valid enclosing scope")
             }
@@ -578,7 +619,7 @@ impl<'a> BindingsBuilder<'a> {
         let mut pred_idx = None;
         let mut pred_function_idx = None;
         if let Some(flow) = self.scopes.current().flow.info.get(&function_identifier.id) {
-            if let FlowStyle::FunctionDef(fidx, _) = flow.style {
+            if let FlowStyle::FunctionDef(fidx, ..) = flow.style {
                 pred_idx = Some(flow.key);
                 pred_function_idx = Some(fidx);
             }
@@ -660,7 +701,10 @@ impl<'a> BindingsBuilder<'a> {
         let mut barrier = false;
         let mut allow_nonlocal_reference = kind == MutableCaptureLookupKind::Nonlocal;
         let mut allow_global_reference = kind == MutableCaptureLookupKind::Global;
-        let mut result = Err(MutableCaptureLookupError::NotFound);
+        let mut result = Err(match kind {
+            MutableCaptureLookupKind::Nonlocal => MutableCaptureLookupError::NonlocalNotFound,
+            MutableCaptureLookupKind::Global => MutableCaptureLookupError::GlobalNotFound,
+        });
         // If there is static info for the name in the current scope and this value is not None
         // set the `annot` field to this value
         let mut static_annot_override = None;
@@ -1117,13 +1161,12 @@ impl<'a> BindingsBuilder<'a> {
         flow
     }
 
-    pub fn setup_loop(&mut self, range: TextRange, narrow_ops: &NarrowOps) {
+    pub fn setup_loop(&mut self, range: TextRange) {
         self.loop_depth += 1;
         let base = mem::take(&mut self.scopes.current_mut().flow);
         // To account for possible assignments to existing names in a loop, we
         // speculatively insert phi keys upfront.
         self.scopes.current_mut().flow = self.insert_phi_keys(base.clone(), range);
-        self.bind_narrow_ops(narrow_ops, range);
         self.scopes
             .current_mut()
             .loops
@@ -1206,6 +1249,16 @@ impl<'a> BindingsBuilder<'a> {
         // Collect all the information that we care about from all branches
         let mut names: SmallMap<Name, (Idx<Key>, Idx<Key>, SmallSet<Idx<Key>>, Vec<FlowStyle>)> =
             SmallMap::with_capacity(visible_branches.first().map_or(0, |x| x.info.len()));
+        // A context manager has only definitely been entered if every branch entered it.
+        let mut entered_context_managers = visible_branches
+            .first()
+            .map(|x| x.entered_context_managers.clone())
+            .unwrap_or_default();
+        entered_context_managers.retain(|k, _| {
+            visible_branches
+                .iter()
+                .all(|x| x.entered_context_managers.contains_key(k))
+        });
         let visible_branches_len = visible_branches.len();
         for flow in visible_branches {
             for (name, info) in flow.info.into_iter_hashed() {
@@ -1258,7 +1311,11 @@ impl<'a> BindingsBuilder<'a> {
                 },
             );
         }
-        Flow { info: res, no_next }
+        Flow {
+            info: res,
+            no_next,
+            entered_context_managers,
+        }
     }
 
     fn merge_loop_into_current(&mut self, mut branches: Vec<Flow>, range: TextRange) {
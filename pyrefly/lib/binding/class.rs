@@ -180,11 +180,13 @@ impl<'a> BindingsBuilder<'a> {
         let mut fields_possibly_defined_by_this_class =
             SmallMap::with_capacity(last_scope.stat.0.len());
         for (name, info) in last_scope.flow.info.iter_hashed() {
-            let is_function_without_return_annotation =
-                if let FlowStyle::FunctionDef(_, has_return_annotation) = info.style {
-                    !has_return_annotation
+            let (is_function_without_return_annotation, is_abstract_method) =
+                if let FlowStyle::FunctionDef(_, has_return_annotation, is_abstract_method) =
+                    info.style
+                {
+                    (!has_return_annotation, is_abstract_method)
                 } else {
-                    false
+                    (false, false)
                 };
             // A name with flow in the last_scope, but whose static is in a parent scope, is a reference to something that isn't a class field.
             // Can occur when we narrow a parent scopes variable, thus producing a fresh flow for it, but no static.
@@ -206,7 +208,11 @@ impl<'a> BindingsBuilder<'a> {
                 };
                 fields_possibly_defined_by_this_class.insert_hashed(
                     name.cloned(),
-                    ClassFieldProperties::new(stat_info.annot.is_some(), stat_info.loc),
+                    ClassFieldProperties::new(
+                        stat_info.annot.is_some(),
+                        is_abstract_method,
+                        stat_info.loc,
+                    ),
                 );
                 self.insert_binding(
                     KeyClassField(class_indices.def_index, name.into_key().clone()),
@@ -232,7 +238,7 @@ impl<'a> BindingsBuilder<'a> {
                     };
                     fields_possibly_defined_by_this_class.insert_hashed(
                         name.clone(),
-                        ClassFieldProperties::new(annotation.is_some(), range),
+                        ClassFieldProperties::new(annotation.is_some(), false, range),
                     );
                     self.insert_binding(
                         KeyClassField(class_indices.def_index, name.key().clone()),
@@ -414,6 +420,7 @@ impl<'a> BindingsBuilder<'a> {
                 member_name.clone(),
                 ClassFieldProperties::new(
                     member_annotation.is_some() || class_kind == SynthesizedClassKind::NamedTuple,
+                    false,
                     range,
                 ),
             );
@@ -60,6 +60,7 @@ use crate::util::visit::Visit;
 
 struct Decorators {
     has_no_type_check: bool,
+    is_abstract_method: bool,
     decorators: Box<[Idx<Key>]>,
 }
 
@@ -401,6 +402,7 @@ impl<'a> BindingsBuilder<'a> {
         let return_type_binding =
             if let Some(implicit_return) = implicit_return_if_inferring_return_type {
                 Binding::ReturnType(Box::new(ReturnType {
+                    range: func_name.range,
                     annot: return_ann_with_range,
                     returns: return_keys,
                     implicit_return,
@@ -433,12 +435,16 @@ impl<'a> BindingsBuilder<'a> {
         let has_no_type_check = decorator_list
             .iter()
             .any(|d| self.as_special_export(&d.expression) == Some(SpecialExport::NoTypeCheck));
+        let is_abstract_method = decorator_list
+            .iter()
+            .any(|d| self.as_special_export(&d.expression) == Some(SpecialExport::AbstractMethod));
 
         let decorators = self
             .ensure_and_bind_decorators(decorator_list)
             .into_boxed_slice();
         Decorators {
             has_no_type_check,
+            is_abstract_method,
             decorators,
         }
     }
@@ -567,6 +573,7 @@ impl<'a> BindingsBuilder<'a> {
             );
         }
 
+        let is_abstract_method = decorators.is_abstract_method;
         self.insert_binding_idx(
             function_idx,
             BindingFunction {
@@ -582,7 +589,11 @@ impl<'a> BindingsBuilder<'a> {
         self.bind_definition(
             &func_name,
             Binding::Function(function_idx, pred_idx, metadata_key),
-            FlowStyle::FunctionDef(function_idx, return_ann_with_range.is_some()),
+            FlowStyle::FunctionDef(
+                function_idx,
+                return_ann_with_range.is_some(),
+                is_abstract_method,
+            ),
         );
     }
 }
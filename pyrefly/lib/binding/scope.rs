@@ -167,6 +167,9 @@ pub struct Flow {
     pub info: SmallMap<Name, FlowInfo>,
     // Should this flow be merged into the next? Flow merging occurs after constructs like branches and loops.
     pub no_next: bool,
+    /// Names entered as context managers by a `with` statement on this flow, mapped to the
+    /// range of the first such use.
+    pub entered_context_managers: SmallMap<Idx<Key>, TextRange>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -187,8 +190,9 @@ pub enum FlowStyle {
     /// would get `foo.bar` here.
     ImportAs(ModuleName),
     /// Am I a function definition? Used to chain overload definitions.
-    /// If so, does my return type have an explicit annotation?
-    FunctionDef(Idx<KeyFunction>, bool),
+    /// If so, does my return type have an explicit annotation, and am I decorated with
+    /// `@abstractmethod`?
+    FunctionDef(Idx<KeyFunction>, bool, bool),
     /// The name is possibly uninitialized (perhaps due to merging branches)
     PossiblyUninitialized,
     /// The name was in an annotated declaration like `x: int` but not initialized
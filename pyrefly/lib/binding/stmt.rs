@@ -15,8 +15,10 @@ use ruff_python_ast::Keyword;
 use ruff_python_ast::Stmt;
 use ruff_python_ast::StmtAssign;
 use ruff_python_ast::StmtImportFrom;
+use ruff_python_ast::StmtRaise;
 use ruff_python_ast::name::Name;
 use ruff_text_size::Ranged;
+use ruff_text_size::TextLen;
 use ruff_text_size::TextRange;
 
 use crate::binding::binding::AnnotationStyle;
@@ -48,6 +50,7 @@ use crate::state::loader::FindError;
 use crate::types::alias::resolve_typeshed_alias;
 use crate::types::special_form::SpecialForm;
 use crate::types::types::Type;
+use crate::util::visit::Visit;
 
 impl<'a> BindingsBuilder<'a> {
     fn bind_unimportable_names(&mut self, x: &StmtImportFrom) {
@@ -246,14 +249,22 @@ impl<'a> BindingsBuilder<'a> {
 
     fn define_nonlocal_name(&mut self, name: &Identifier) {
         let key = Key::Definition(ShortIdentifier::new(name));
-        let binding =
+        let binding = if matches!(self.scopes.current().kind, ScopeKind::Module) {
+            self.error(
+                name.range,
+                "`nonlocal` declaration is not allowed at module level".to_owned(),
+                ErrorKind::InvalidSyntax,
+            );
+            Binding::Type(Type::any_error())
+        } else {
             match self.lookup_mutable_captured_name(&name.id, MutableCaptureLookupKind::Nonlocal) {
                 Ok(found) => Binding::Forward(found),
                 Err(error) => {
                     self.error(name.range, error.message(name), ErrorKind::UnknownName);
                     Binding::Type(Type::any_error())
                 }
-            };
+            }
+        };
         self.insert_binding(key, binding);
     }
 
@@ -297,9 +308,19 @@ impl<'a> BindingsBuilder<'a> {
             }
             Stmt::Delete(mut x) => {
                 for target in &mut x.targets {
+                    let ann = match target {
+                        Expr::Name(name) => self
+                            .scopes
+                            .current()
+                            .stat
+                            .0
+                            .get(&name.id)
+                            .and_then(|info| info.annot),
+                        _ => None,
+                    };
                     self.insert_binding(
                         KeyExpect(target.range()),
-                        BindingExpect::Delete(Box::new(target.clone())),
+                        BindingExpect::Delete(ann, Box::new(target.clone())),
                     );
                     if let Expr::Name(name) = target {
                         let idx = self.ensure_mutable_name(name);
@@ -596,8 +617,22 @@ impl<'a> BindingsBuilder<'a> {
                 }
             }
             Stmt::For(mut x) => {
+                if let Expr::Name(target) = &*x.target
+                    && let Expr::Name(iter) = &*x.iter
+                    && target.id == iter.id
+                    && uses_name(&x.body, &target.id)
+                {
+                    self.error(
+                        target.range,
+                        format!(
+                            "Loop variable `{}` shadows the iterable it loops over, so inside the loop it refers to an element",
+                            target.id
+                        ),
+                        ErrorKind::ShadowedIterable,
+                    );
+                }
                 self.ensure_expr(&mut x.iter);
-                self.setup_loop(x.range, &NarrowOps::new());
+                self.setup_loop(x.range);
                 let make_binding =
                     |ann| Binding::IterableValue(ann, *x.iter.clone(), IsAsync::new(x.is_async));
                 self.bind_target(&mut x.target, &make_binding);
@@ -606,8 +641,11 @@ impl<'a> BindingsBuilder<'a> {
             }
             Stmt::While(mut x) => {
                 let narrow_ops = NarrowOps::from_expr(self, Some(&x.test));
-                self.setup_loop(x.range, &narrow_ops);
+                self.setup_loop(x.range);
                 self.ensure_expr(&mut x.test);
+                // Narrow after binding the test, so that any names it assigns with a walrus
+                // are narrowed rather than their values from before the test.
+                self.bind_narrow_ops(&narrow_ops, x.range);
                 let range = x.test.range();
                 self.insert_binding(Key::Anon(range), Binding::Expr(None, *x.test.clone()));
                 // Typecheck the test condition during solving.
@@ -631,7 +669,22 @@ impl<'a> BindingsBuilder<'a> {
                 // is carried over to the else branch.
                 let mut negated_prev_ops = NarrowOps::new();
                 let mut implicit_else = true;
-                for (range, test, body) in Ast::if_branches_owned(x) {
+                let if_branches = Ast::if_branches_owned(x).collect::<Vec<_>>();
+                // The `elif` and `else` clauses after each branch, which are dead if its
+                // condition is always true.
+                let clause_ranges = if_branches
+                    .iter()
+                    .map(|(range, _, _)| *range)
+                    .collect::<Vec<_>>();
+                let later_clauses = (0..if_branches.len())
+                    .map(|i| {
+                        let next = clause_ranges.get(i + 1)?;
+                        Some(TextRange::new(next.start(), clause_ranges.last()?.end()))
+                    })
+                    .collect::<Vec<_>>();
+                for ((range, test, body), later_clauses) in
+                    if_branches.into_iter().zip(later_clauses)
+                {
                     let b = self.sys_info.evaluate_bool_opt(test.as_ref());
                     if b == Some(false) {
                         continue; // We won't pick this branch
@@ -641,12 +694,26 @@ impl<'a> BindingsBuilder<'a> {
                     let new_narrow_ops = NarrowOps::from_expr(self, test.as_ref());
                     if let Some(mut e) = test {
                         self.ensure_expr(&mut e);
-                        self.insert_binding(Key::Anon(e.range()), Binding::Expr(None, e.clone()));
+                        let test_idx = self
+                            .insert_binding(Key::Anon(e.range()), Binding::Expr(None, e.clone()));
                         // Typecheck the test condition during solving.
                         self.insert_binding(
                             KeyExpect(e.range()),
                             BindingExpect::Bool(Box::new(e.clone()), range),
                         );
+                        if b.is_none()
+                            && let (Some(first), Some(last)) = (body.first(), body.last())
+                        {
+                            self.insert_binding(
+                                KeyExpect(range),
+                                BindingExpect::UnreachableBranch(
+                                    test_idx,
+                                    Box::new(e.clone()),
+                                    TextRange::new(first.start(), last.end()),
+                                    later_clauses,
+                                ),
+                            );
+                        }
                     } else {
                         implicit_else = false;
                     }
@@ -678,10 +745,27 @@ impl<'a> BindingsBuilder<'a> {
                     self.ensure_expr(&mut item.context_expr);
                     let item_range = item.range();
                     let expr_range = item.context_expr.range();
+                    let name_idx = match &item.context_expr {
+                        Expr::Name(name) => self.lookup_name(&name.id, LookupKind::Regular).ok(),
+                        _ => None,
+                    };
                     let context_idx = self.insert_binding(
                         Key::ContextExpr(expr_range),
                         Binding::Expr(None, item.context_expr),
                     );
+                    if let Some(name_idx) = name_idx {
+                        // Entering the same value twice is only a problem for some context managers,
+                        // we leave it to the solver to check the type.
+                        let entered = &mut self.scopes.current_mut().flow.entered_context_managers;
+                        if let Some(first) = entered.get(&name_idx).copied() {
+                            self.insert_binding(
+                                KeyExpect(expr_range),
+                                BindingExpect::ReusedContextManager(context_idx, first),
+                            );
+                        } else {
+                            entered.insert(name_idx, expr_range);
+                        }
+                    }
                     if let Some(mut opts) = item.optional_vars {
                         let make_binding = |ann: Option<Idx<KeyAnnotation>>| {
                             Binding::ContextValue(ann, context_idx, expr_range, kind)
@@ -736,20 +820,29 @@ impl<'a> BindingsBuilder<'a> {
                     base = self.scopes.current().flow.clone();
                     let range = h.range();
                     let h = h.except_handler().unwrap(); // Only one variant for now
+                    let reraises = handler_reraises(&h.body, h.name.as_ref());
+                    if h.type_.is_none() && !reraises {
+                        self.error(
+                            TextRange::at(range.start(), "except".text_len()),
+                            "Bare `except:` also catches `KeyboardInterrupt` and `SystemExit`, use `except Exception:` instead"
+                                .to_owned(),
+                            ErrorKind::BroadExcept,
+                        );
+                    }
                     if let Some(name) = h.name
                         && let Some(mut type_) = h.type_
                     {
                         self.ensure_expr(&mut type_);
                         self.bind_definition(
                             &name,
-                            Binding::ExceptionHandler(type_, x.is_star),
+                            Binding::ExceptionHandler(type_, x.is_star, reraises),
                             FlowStyle::None,
                         );
                     } else if let Some(mut type_) = h.type_ {
                         self.ensure_expr(&mut type_);
                         self.insert_binding(
                             Key::Anon(range),
-                            Binding::ExceptionHandler(type_, x.is_star),
+                            Binding::ExceptionHandler(type_, x.is_star, reraises),
                         );
                     }
                     self.stmts(h.body);
@@ -932,3 +1025,44 @@ impl<'a> BindingsBuilder<'a> {
         }
     }
 }
+
+/// Does the body of an `except` handler re-raise the exception it caught, either with a bare
+/// `raise` or by raising the name the exception is bound to?
+fn handler_reraises(body: &[Stmt], name: Option<&Identifier>) -> bool {
+    fn f(x: &Stmt, name: Option<&Identifier>, res: &mut bool) {
+        match x {
+            Stmt::Raise(StmtRaise { exc: None, .. }) => *res = true,
+            Stmt::Raise(StmtRaise {
+                exc: Some(box Expr::Name(exc)),
+                cause: None,
+                ..
+            }) if name.is_some_and(|name| name.id == exc.id) => *res = true,
+            // A `raise` in a nested scope doesn't run as part of the handler.
+            Stmt::FunctionDef(_) | Stmt::ClassDef(_) => {}
+            _ => x.recurse(&mut |x| f(x, name, res)),
+        }
+    }
+    let mut res = false;
+    for x in body {
+        f(x, name, &mut res);
+    }
+    res
+}
+
+/// Does any statement in `body` mention `name`?
+fn uses_name(body: &[Stmt], name: &Name) -> bool {
+    fn f(x: &Expr, name: &Name, res: &mut bool) {
+        if let Expr::Name(x) = x
+            && x.id == *name
+        {
+            *res = true;
+        } else {
+            x.recurse(&mut |x| f(x, name, res));
+        }
+    }
+    let mut res = false;
+    for x in body {
+        x.visit(&mut |x| f(x, name, &mut res));
+    }
+    res
+}
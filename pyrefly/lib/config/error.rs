@@ -16,7 +16,8 @@ use crate::error::kind::ErrorKind;
 /// The boolean in the map represents whether the error is enabled or disabled
 /// (true = show error, false = don't show error).
 /// Not all error kinds are required to be defined in this map. Any that are missing
-/// will be treated as `<error-kind> = true`.
+/// will use the default for the error kind, which is `<error-kind> = true` for everything
+/// except opt-in warnings.
 #[derive(Debug, PartialEq, Eq, Deserialize, Serialize, Clone, Default)]
 #[serde(transparent)]
 pub struct ErrorDisplayConfig(HashMap<ErrorKind, bool>);
@@ -27,9 +28,12 @@ impl ErrorDisplayConfig {
     }
 
     /// Gets whether the given `ErrorKind` is enabled. If the value isn't
-    /// found, then assume it should be enabled.
+    /// found, then fall back to the default for that kind.
     pub fn is_enabled(&self, kind: ErrorKind) -> bool {
-        self.0.get(&kind) != Some(&false)
+        self.0
+            .get(&kind)
+            .copied()
+            .unwrap_or_else(|| kind.is_enabled_by_default())
     }
 }
 
@@ -12,6 +12,7 @@ pub const AENTER: Name = Name::new_static("__aenter__");
 pub const AEXIT: Name = Name::new_static("__aexit__");
 pub const ALL: Name = Name::new_static("__all__");
 pub const CALL: Name = Name::new_static("__call__");
+pub const CLASS_GETITEM: Name = Name::new_static("__class_getitem__");
 pub const CONTAINS: Name = Name::new_static("__contains__");
 pub const DEBUG: Name = Name::new_static("__debug__");
 pub const DELITEM: Name = Name::new_static("__delitem__");
@@ -39,6 +40,7 @@ pub const NEW: Name = Name::new_static("__new__");
 #[expect(dead_code)]
 pub const NEXT: Name = Name::new_static("__next__");
 pub const POS: Name = Name::new_static("__pos__");
+pub const POST_INIT: Name = Name::new_static("__post_init__");
 pub const SET: Name = Name::new_static("__set__");
 pub const SETITEM: Name = Name::new_static("__setitem__");
 pub const BOOL: Name = Name::new_static("__bool__");
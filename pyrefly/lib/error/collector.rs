@@ -140,6 +140,15 @@ impl ErrorCollector {
         self.errors.lock().len()
     }
 
+    /// The first line of the first error, in source order, if there are any errors.
+    pub fn first_message(&self) -> Option<String> {
+        self.errors
+            .lock()
+            .iter()
+            .next()
+            .and_then(|err| err.msg().lines().next().map(|line| line.to_owned()))
+    }
+
     pub fn collect_into(&self, error_config: &ErrorConfig, result: &mut CollectedErrors) {
         let mut errors = self.errors.lock();
         if !(self.module_info.is_generated() && error_config.ignore_errors_in_generated_code) {
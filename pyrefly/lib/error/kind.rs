@@ -75,11 +75,20 @@ pub enum ErrorKind {
     /// An error caused by unpacking.
     /// e.g. attempting to unpack an iterable into the wrong number of variables.
     BadUnpacking,
+    /// An `except` handler catches `BaseException`, including `KeyboardInterrupt` and `SystemExit`.
+    BroadExcept,
+    /// Comparing a value to a class object with `==`, probably meant to be `isinstance`.
+    CompareToClass,
+    /// An `if` or `while` condition that is always true or always false, judging by its type.
+    ConstantCondition,
     /// Attempting to `del` something that cannot be deleted
     DeleteError,
     /// An error related to the import machinery.
     /// e.g. failed to import a module.
     ImportError,
+    /// The `return` statements of an unannotated function produce types with no common base
+    /// besides `object`.
+    InconsistentReturns,
     /// Attempting to access a container with an incorrect index.
     /// This only occurs when pyre can statically verify that the index is incorrect.
     IndexError,
@@ -130,6 +139,9 @@ pub enum ErrorKind {
     NoAccess,
     /// Attempting to call an overloaded function, but none of the signatures match.
     NoMatchingOverload,
+    /// An `in` or `not in` test whose left operand can never be equal to an element of the
+    /// container, e.g. `"x" in [1, 2, 3]`.
+    NonOverlappingContainer,
     /// Attempting to use something that isn't a type where a type is expected.
     /// This is a very general error and should be used sparingly.
     NotAType,
@@ -141,8 +153,21 @@ pub enum ErrorKind {
     ParseError,
     /// The attribute exists but cannot be modified.
     ReadOnly,
+    /// A `typing.cast` that has no useful effect, such as a cast to `Any` or a cast of a cast.
+    RedundantCast,
+    /// A `Literal` member of a union annotation that is already covered by another member,
+    /// e.g. `int | Literal[3]`.
+    RedundantLiteral,
+    /// A context manager that can only be entered once (e.g. one produced by
+    /// `@contextlib.contextmanager`) is used in more than one `with` statement.
+    ReusedContextManager,
     /// Raised by a call to reveal_type().
     RevealType,
+    /// A `for` loop variable has the same name as the iterable it loops over, e.g. `for x in x:`.
+    ShadowedIterable,
+    /// A definition in an implementation file is inconsistent with its declaration
+    /// in the corresponding stub file.
+    StubMismatch,
     /// An error related to type alias usage or definition.
     TypeAliasError,
     /// An error related to TypedDict keys.
@@ -152,6 +177,12 @@ pub enum ErrorKind {
     UnexpectedKeyword,
     /// Attempting to use a name that may be unbound or uninitialized
     UnboundName,
+    /// A branch of an `if` statement that can never run, because a condition's truthiness is
+    /// known from its type.
+    UnreachableBranch,
+    /// An overload that can never be selected, because an earlier overload accepts every call
+    /// that it accepts.
+    UnreachableOverload,
     /// Attempting to use a name that is not defined.
     UnknownName,
     /// Attempting to apply an operator to arguments that do not support it.
@@ -180,9 +211,39 @@ impl ErrorKind {
     pub fn severity(self) -> Severity {
         match self {
             ErrorKind::RevealType => Severity::Info,
+            ErrorKind::BroadExcept
+            | ErrorKind::CompareToClass
+            | ErrorKind::ConstantCondition
+            | ErrorKind::InconsistentReturns
+            | ErrorKind::NonOverlappingContainer
+            | ErrorKind::RedundantCast
+            | ErrorKind::RedundantLiteral
+            | ErrorKind::ReusedContextManager
+            | ErrorKind::ShadowedIterable
+            | ErrorKind::StubMismatch
+            | ErrorKind::UnreachableBranch
+            | ErrorKind::UnreachableOverload => Severity::Warn,
             _ => Severity::Error,
         }
     }
+
+    /// Whether errors of this kind are shown when the config doesn't mention them.
+    pub fn is_enabled_by_default(self) -> bool {
+        !matches!(
+            self,
+            ErrorKind::BroadExcept
+                | ErrorKind::CompareToClass
+                | ErrorKind::ConstantCondition
+                | ErrorKind::InconsistentReturns
+                | ErrorKind::NonOverlappingContainer
+                | ErrorKind::RedundantCast
+                | ErrorKind::RedundantLiteral
+                | ErrorKind::ReusedContextManager
+                | ErrorKind::ShadowedIterable
+                | ErrorKind::StubMismatch
+                | ErrorKind::UnreachableBranch
+        )
+    }
 }
 #[cfg(test)]
 mod tests {
@@ -24,6 +24,7 @@ use crate::export::definitions::DunderAllEntry;
 use crate::graph::calculation::Calculation;
 use crate::module::module_info::ModuleInfo;
 use crate::module::module_name::ModuleName;
+use crate::module::module_path::ModulePath;
 use crate::state::loader::FindError;
 use crate::sys_info::SysInfo;
 
@@ -49,11 +50,13 @@ pub enum ExportLocation {
     OtherModule(ModuleName),
 }
 
-#[derive(Debug, Default, Clone, Dupe)]
+#[derive(Debug, Clone, Dupe)]
 pub struct Exports(Arc<ExportsInner>);
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 struct ExportsInner {
+    /// The path of the module these are the exports of.
+    path: ModulePath,
     /// The underlying definitions.
     /// Note that these aren't actually required, once we have calculated the other fields,
     /// but they take up very little space, so not worth the hassle to detect when
@@ -90,6 +93,7 @@ impl Exports {
         );
         definitions.ensure_dunder_all(module_info.path().style());
         Self(Arc::new(ExportsInner {
+            path: module_info.path().dupe(),
             definitions,
             wildcard: Calculation::new(),
             exports: Calculation::new(),
@@ -126,6 +130,11 @@ impl Exports {
         self.0.wildcard.calculate(f).unwrap_or_default()
     }
 
+    /// The path of the module, which may be a stub rather than the implementation.
+    pub fn path(&self) -> &ModulePath {
+        &self.0.path
+    }
+
     /// Get the docstring for this module.
     pub fn docstring(&self) -> Option<&DocString> {
         self.0.docstring.as_ref()
@@ -40,6 +40,7 @@ pub enum SpecialExport {
     OsExit,
     Len,
     NoTypeCheck,
+    AbstractMethod,
 }
 
 #[derive(Debug)]
@@ -80,6 +81,7 @@ impl SpecialExport {
             "_exit" => Some(Self::OsExit),
             "len" => Some(Self::Len),
             "no_type_check" => Some(Self::NoTypeCheck),
+            "abstractmethod" => Some(Self::AbstractMethod),
             _ => None,
         }
     }
@@ -108,6 +110,7 @@ impl SpecialExport {
             Self::Exit => matches!(m.as_str(), "sys" | "builtins"),
             Self::Quit => matches!(m.as_str(), "builtins"),
             Self::OsExit => matches!(m.as_str(), "os"),
+            Self::AbstractMethod => matches!(m.as_str(), "abc"),
         }
     }
 
@@ -19,7 +19,9 @@ use crate::alt::answers::LookupAnswer;
 use crate::dunder;
 use crate::solver::solver::Subset;
 use crate::types::callable::Callable;
+use crate::types::callable::FuncMetadata;
 use crate::types::callable::Function;
+use crate::types::callable::FunctionKind;
 use crate::types::callable::Param;
 use crate::types::callable::ParamList;
 use crate::types::callable::Params;
@@ -33,6 +35,7 @@ use crate::types::type_var::Restriction;
 use crate::types::type_var::Variance;
 use crate::types::types::Forall;
 use crate::types::types::Forallable;
+use crate::types::types::Overload;
 use crate::types::types::TParams;
 use crate::types::types::Type;
 
@@ -654,6 +657,25 @@ impl<'a, Ans: LookupAnswer> Subset<'a, Ans> {
             (l, Type::Union(us)) => us.iter().any(|u| self.is_subset_eq(l, u)),
             (Type::Intersect(ls), u) => ls.iter().any(|l| self.is_subset_eq(l, u)),
             (Type::Module(_), Type::ClassType(cls)) if cls.has_qname("types", "ModuleType") => true,
+            (
+                Type::Function(box Function {
+                    signature: _,
+                    metadata:
+                        FuncMetadata {
+                            kind: FunctionKind::Partial(cls),
+                            ..
+                        },
+                })
+                | Type::Overload(Overload {
+                    signatures: _,
+                    metadata:
+                        box FuncMetadata {
+                            kind: FunctionKind::Partial(cls),
+                            ..
+                        },
+                }),
+                Type::ClassType(_),
+            ) => self.is_subset_eq(&Type::ClassType((**cls).clone()), want),
             (
                 Type::Function(_)
                 | Type::Overload(_)
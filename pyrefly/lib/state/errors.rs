@@ -13,6 +13,7 @@ use starlark_map::small_map::SmallMap;
 use crate::config::config::ConfigFile;
 use crate::error::collector::CollectedErrors;
 use crate::error::expectation::Expectation;
+use crate::error::kind::ErrorKind;
 use crate::module::ignore::Ignore;
 use crate::module::module_path::ModulePath;
 use crate::state::load::Load;
@@ -40,6 +41,16 @@ impl Errors {
         errors
     }
 
+    /// The number of errors of each kind that will be reported to the user, i.e. excluding
+    /// errors suppressed by ignore comments or disabled by configuration.
+    pub fn diagnostic_count_by_kind(&self) -> SmallMap<ErrorKind, usize> {
+        let mut counts = SmallMap::new();
+        for err in self.collect_errors().shown {
+            *counts.entry(err.error_kind()).or_default() += 1;
+        }
+        counts
+    }
+
     pub fn collect_ignores(&self) -> SmallMap<&ModulePath, &Ignore> {
         let mut ignore_collection: SmallMap<&ModulePath, &Ignore> = SmallMap::new();
         for (load, _) in &self.loads {
@@ -620,6 +620,7 @@ impl<'a> Transaction<'a> {
             }
 
             let stdlib = self.get_stdlib(&module_data.handle);
+            let config = module_data.config.read().dupe();
             let set = compute(&Context {
                 require,
                 module: module_data.handle.module(),
@@ -629,10 +630,9 @@ impl<'a> Transaction<'a> {
                 uniques: &self.data.state.uniques,
                 stdlib: &stdlib,
                 lookup: &self.lookup(module_data.dupe()),
-                untyped_def_behavior: module_data
-                    .config
-                    .read()
+                untyped_def_behavior: config
                     .untyped_def_behavior(module_data.handle.path().as_path()),
+                error_config: config.errors(module_data.handle.path().as_path()),
             });
             {
                 let mut changed = false;
@@ -1237,6 +1237,7 @@ impl<'a> Transaction<'a> {
             let mut alt = Steps::default();
             let lock = m.state.read();
             let stdlib = self.get_stdlib(&m.handle);
+            let config = m.config.read().dupe();
             let ctx = Context {
                 require: lock.require.get(self.data.require),
                 module: m.handle.module(),
@@ -1246,10 +1247,8 @@ impl<'a> Transaction<'a> {
                 uniques: &self.data.state.uniques,
                 stdlib: &stdlib,
                 lookup: &self.lookup(m.dupe()),
-                untyped_def_behavior: m
-                    .config
-                    .read()
-                    .untyped_def_behavior(m.handle.path().as_path()),
+                untyped_def_behavior: config.untyped_def_behavior(m.handle.path().as_path()),
+                error_config: config.errors(m.handle.path().as_path()),
             };
             let mut step = Step::Load; // Start at AST (Load.next)
             alt.load = lock.steps.load.dupe();
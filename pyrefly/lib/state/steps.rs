@@ -18,6 +18,7 @@ use crate::alt::answers::LookupAnswer;
 use crate::alt::answers::Solutions;
 use crate::binding::bindings::Bindings;
 use crate::config::base::UntypedDefBehavior;
+use crate::config::error::ErrorDisplayConfig;
 use crate::error::style::ErrorStyle;
 use crate::export::exports::Exports;
 use crate::export::exports::LookupExport;
@@ -41,6 +42,7 @@ pub struct Context<'a, Lookup> {
     pub stdlib: &'a Stdlib,
     pub lookup: &'a Lookup,
     pub untyped_def_behavior: UntypedDefBehavior,
+    pub error_config: &'a ErrorDisplayConfig,
 }
 
 #[derive(Debug, Default, Dupe, Clone)]
@@ -178,6 +180,7 @@ impl Step {
             ctx.uniques,
             enable_trace,
             ctx.untyped_def_behavior,
+            ctx.error_config.clone(),
         );
         let answers = Answers::new(&bindings, solver, enable_index, enable_trace);
         Arc::new((bindings, Arc::new(answers)))
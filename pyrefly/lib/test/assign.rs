@@ -5,7 +5,12 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use ruff_python_ast::name::Name;
+
+use crate::binding::binding::Binding;
+use crate::binding::binding::KeyExport;
 use crate::test::util::TestEnv;
+use crate::test::util::solve_in_main;
 use crate::testcase;
 
 testcase!(
@@ -737,3 +742,29 @@ def f(x):
     x = "None" 
     "#,
 );
+
+#[test]
+fn test_widen_to_declared() {
+    let (narrowed, declared) = solve_in_main(
+        r#"
+x: int | None = None
+x = 1
+"#,
+        |solver| {
+            let bindings = solver.bindings();
+            let export = bindings.key_to_idx(&KeyExport(Name::new_static("x")));
+            let idx = match bindings.get(export) {
+                Binding::Forward(idx) | Binding::AnnotatedType(_, box Binding::Forward(idx)) => {
+                    *idx
+                }
+                _ => unreachable!(),
+            };
+            solver.widen_to_declared(idx)
+        },
+    );
+    assert_eq!(narrowed.to_string(), "Literal[1]");
+    assert_eq!(
+        declared.map(|t| t.to_string()).as_deref(),
+        Some("int | None")
+    );
+}
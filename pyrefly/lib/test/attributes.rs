@@ -5,7 +5,13 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use ruff_python_ast::name::Name;
+
+use crate::alt::class::class_field::AttributeKind;
 use crate::test::util::TestEnv;
+use crate::test::util::get_export;
+use crate::test::util::get_export_class;
+use crate::test::util::solve_in_main;
 use crate::testcase;
 
 testcase!(
@@ -913,3 +919,135 @@ class A[T]:
 assert_type(A.f(A[int]()), int)
     "#,
 );
+
+#[test]
+fn test_method_signature_string() {
+    let signatures = solve_in_main(
+        r#"
+from typing import Self, overload
+class C:
+    x: int = 0
+    def m(self, x: int) -> Self: ...
+    @staticmethod
+    def s(x: int) -> int: ...
+    @overload
+    def o(self, x: int) -> int: ...
+    @overload
+    def o(self, x: str) -> str: ...
+    def o(self, x: int | str) -> int | str: ...
+"#,
+        |solver| {
+            let cls = get_export_class(solver, "C");
+            ["m", "s", "o", "x"]
+                .map(|name| solver.method_signature_string(&cls, &Name::new_static(name)))
+        },
+    );
+    assert_eq!(
+        signatures.each_ref().map(|x| x.as_deref()),
+        [
+            Some("(x: int) -> C"),
+            Some("(x: int) -> int"),
+            Some("(x: int) -> int\n(x: str) -> str"),
+            None,
+        ]
+    );
+}
+
+#[test]
+fn test_definition_location() {
+    let locations = solve_in_main(
+        r#"
+class C:
+    def m(self) -> None: ...
+def f() -> None: ...
+type A = int
+c = C()
+bm = c.m
+n = len
+i = 1
+"#,
+        |solver| {
+            ["C", "c", "f", "A", "bm", "n", "i"].map(|name| {
+                let ty = get_export(solver, name);
+                solver.definition_location(&ty).map(|(path, range)| {
+                    let file = path.as_path().file_name().unwrap().to_string_lossy();
+                    if path == *solver.module_info().path() {
+                        format!("{file}:{}", solver.module_info().code_at(range))
+                    } else {
+                        file.into_owned()
+                    }
+                })
+            })
+        },
+    );
+    assert_eq!(
+        locations.each_ref().map(|x| x.as_deref()),
+        [
+            Some("main.py:C"),
+            Some("main.py:C"),
+            Some("main.py:f"),
+            Some("main.py:A"),
+            Some("main.py:m"),
+            Some("builtins.pyi"),
+            None,
+        ]
+    );
+}
+
+#[test]
+fn test_attribute_kind() {
+    let kinds = solve_in_main(
+        r#"
+from typing import ClassVar
+class B:
+    inherited: int
+class C(B):
+    x: int = 0
+    y: ClassVar[int] = 0
+    z: str
+    def __init__(self) -> None:
+        self.w = 1
+    def m(self) -> None: ...
+    @classmethod
+    def c(cls) -> None: ...
+    @staticmethod
+    def s() -> None: ...
+    @property
+    def p(self) -> int: ...
+    class N: ...
+"#,
+        |solver| {
+            let cls = get_export_class(solver, "C");
+            [
+                "x",
+                "y",
+                "z",
+                "w",
+                "m",
+                "c",
+                "s",
+                "p",
+                "N",
+                "inherited",
+                "missing",
+            ]
+            .map(|name| solver.attribute_kind(&cls, &Name::new_static(name)))
+        },
+    );
+    assert_eq!(
+        kinds,
+        [
+            Some(AttributeKind::InstanceVar),
+            Some(AttributeKind::ClassVar),
+            Some(AttributeKind::InstanceVar),
+            Some(AttributeKind::InstanceVar),
+            Some(AttributeKind::Method),
+            Some(AttributeKind::ClassMethod),
+            Some(AttributeKind::StaticMethod),
+            Some(AttributeKind::Property),
+            Some(AttributeKind::NestedClass),
+            Some(AttributeKind::InstanceVar),
+            None,
+        ]
+    );
+}
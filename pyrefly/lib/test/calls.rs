@@ -5,7 +5,18 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use ruff_python_ast::Expr;
+use ruff_python_ast::Keyword;
+use ruff_text_size::TextRange;
+use ruff_text_size::TextSize;
+
+use crate::alt::callable::CallArg;
+use crate::ruff::ast::Ast;
+use crate::test::util::get_export;
+use crate::test::util::solve_in_main;
 use crate::testcase;
+use crate::types::types::Type;
+use crate::util::prelude::SliceExt;
 
 testcase!(
     test_generic_call_happy_case,
@@ -50,3 +61,133 @@ assert_type(A.__new__(B[int]), B[int])
 assert_type(A.__new__(C[int]), Self)
     "#,
 );
+
+testcase!(
+    test_unannotated_param_with_literal_default,
+    r#"
+from typing import Any, assert_type
+def f(x=0, y="a", z=None, w=-1.5, flag=False):
+    assert_type(x, int)
+    assert_type(y, str)
+    assert_type(z, Any)
+    assert_type(w, float)
+    assert_type(flag, bool)
+f(1, "b", 3, 2.0, True)
+f("no")  # E: Argument `Literal['no']` is not assignable to parameter `x` with type `int`
+    "#,
+);
+
+testcase!(
+    test_functools_partial,
+    r#"
+from functools import partial
+from typing import assert_type, reveal_type
+
+def f(a: int, b: str, c: bytes = b"") -> int: ...
+
+g = partial(f, 1)
+reveal_type(g)  # E: revealed type: (b: str, c: bytes = ...) -> int
+assert_type(g("x"), int)
+g(2)  # E: Argument `Literal[2]` is not assignable to parameter `b` with type `str`
+
+h = partial(f, b="x")
+reveal_type(h)  # E: revealed type: (a: int, *, b: str, c: bytes) -> int
+h(1, b="y")
+h(1, "y")  # E: Expected 1 positional argument, got 2
+
+partial(f, "x")  # E: Argument `Literal['x']` is not assignable to parameter `a` with type `int` in function `f`
+partial(f, 1, "x", b"", 4)  # E: Expected 3 positional arguments, got 4
+partial(f, d=1)  # E: Unexpected keyword argument `d`
+
+class A:
+    def m(self, x: int, y: str) -> None: ...
+reveal_type(partial(A().m, 1))  # E: revealed type: (y: str) -> None
+    "#,
+);
+
+testcase!(
+    test_functools_partial_is_partial,
+    r#"
+import functools
+from functools import partial
+from typing import Any, Callable, assert_type
+
+def f(a: int, b: str) -> int: ...
+
+g = partial(f, 1)
+assert_type(g.func, Callable[..., int])
+assert_type(g.args, tuple[Any, ...])
+assert_type(g.keywords, dict[str, Any])
+p: functools.partial[int] = partial(f, 1)
+q: partial[str] = partial(f, 1)  # E: is not assignable to `partial[str]`
+c: Callable[[str], int] = partial(f, 1)
+    "#,
+);
+
+testcase!(
+    test_functools_partial_overload,
+    r#"
+from functools import partial
+from typing import overload, reveal_type
+
+@overload
+def f(x: int, y: int) -> int: ...
+@overload
+def f(x: str, y: str) -> str: ...
+def f(x: int | str, y: int | str) -> int | str:
+    return x
+
+reveal_type(partial(f, 1))  # E: revealed type: (y: int) -> int
+reveal_type(partial(f, y="x"))  # E: revealed type: (x: str, *, y: str) -> str
+reveal_type(partial(f))  # E: revealed type: Overload[(x: int, y: int) -> int, (x: str, y: str) -> str]
+partial(f, 1.0)  # E: accepts the arguments bound by `functools.partial`
+    "#,
+);
+
+#[test]
+fn test_infer_call_result() {
+    let results = solve_in_main(
+        r#"
+def f[T](x: T, y: int) -> list[T]: ...
+def g[T](x: int, *, k: T) -> list[T]: ...
+def h(x: int, *, k: str) -> bytes: ...
+s: str = ""
+n: int = 0
+"#,
+        |solver| {
+            let get = |name| get_export(solver, name);
+            let (f, g, h, s, n) = (get("f"), get("g"), get("h"), get("s"), get("n"));
+            let keywords = |call: &str| match Ast::parse_expr(call, TextSize::default()).unwrap() {
+                Expr::Call(call) => call.arguments.keywords.into_vec(),
+                _ => unreachable!(),
+            };
+            let call = |callee: &Type, args: &[&Type], keywords: &[Keyword]| {
+                let args = args.map(|ty| CallArg::Type(ty, TextRange::default()));
+                solver
+                    .infer_call_result(callee, &args, keywords, TextRange::default())
+                    .map(|ty| ty.to_string())
+            };
+            [
+                call(&f, &[&s, &n], &[]),
+                // Missing and mismatched arguments are tolerated.
+                call(&f, &[&s], &[]),
+                call(&f, &[&s, &s, &s], &[]),
+                // Keyword-only parameters are matched by name, and may be missing.
+                call(&g, &[&n], &keywords("g(k=b'')")),
+                call(&h, &[&n], &[]),
+                call(&n, &[], &[]),
+            ]
+        },
+    );
+    assert_eq!(
+        results.each_ref().map(|x| x.as_deref()),
+        [
+            Some("list[str]"),
+            Some("list[str]"),
+            Some("list[str]"),
+            Some("list[bytes]"),
+            Some("bytes"),
+            None
+        ]
+    );
+}
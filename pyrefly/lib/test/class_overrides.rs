@@ -37,6 +37,33 @@ class B(A):
  "#,
 );
 
+testcase!(
+    test_override_narrowed_param,
+    r#"
+class A:
+    def f(self, x: object) -> object: ...
+    def g(self, *, key: int) -> None: ...
+
+class B(A):
+    def f(self, x: int) -> object: ...  # E: Class member `B.f` overrides parent class `A` in an inconsistent manner\n  Parameter `x` has type `int`, which does not accept `object` from the overridden method
+    def g(self, *, key: bool) -> None: ...  # E: Parameter `key` has type `bool`, which does not accept `int` from the overridden method
+ "#,
+);
+
+testcase!(
+    test_override_widened_return,
+    r#"
+class A:
+    def f(self, x: int) -> int: ...
+
+class B(A):
+    def f(self, x: object) -> bool: ...  # OK: wider parameter, narrower return
+
+class C(A):
+    def f(self, x: int) -> object: ...  # E: Return type `object` is not assignable to `int`, the return type of the overridden method
+ "#,
+);
+
 testcase!(
     test_override_basic_field,
     r#"
@@ -5,6 +5,8 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::test::util::get_export;
+use crate::test::util::solve_in_main;
 use crate::testcase;
 
 testcase!(
@@ -190,3 +192,34 @@ def f(b: B):
     assert_type(b.x3, int)
     "#,
 );
+
+#[test]
+fn test_common_supertype() {
+    let supertypes = solve_in_main(
+        r#"
+from typing import Literal
+class A: ...
+class B(A): ...
+class C(A): ...
+class D: ...
+a: A = A()
+b: B = B()
+c: C = C()
+d: D = D()
+one: Literal[1] = 1
+true: Literal[True] = True
+"#,
+        |solver| {
+            let get = |name| get_export(solver, name);
+            [
+                ("b", "c"),
+                ("a", "b"),
+                ("b", "a"),
+                ("c", "d"),
+                ("one", "true"),
+            ]
+            .map(|(x, y)| solver.common_supertype(&get(x), &get(y)).to_string())
+        },
+    );
+    assert_eq!(supertypes, ["A", "A", "A", "C | D", "int"]);
+}
@@ -5,6 +5,8 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::test::util::get_export_class;
+use crate::test::util::solve_in_main;
 use crate::testcase;
 
 testcase!(
@@ -394,3 +396,76 @@ class C[T]:
 assert_type(C(0), C[int])
     "#,
 );
+
+testcase!(
+    test_instantiate_abstract_class,
+    r#"
+from abc import ABC, abstractmethod
+class Shape(ABC):
+    @abstractmethod
+    def area(self) -> float: ...
+    @abstractmethod
+    def perimeter(self) -> float: ...
+class Square(Shape):
+    def area(self) -> float:
+        return 1.0
+class UnitSquare(Square):
+    def perimeter(self) -> float:
+        return 4.0
+Shape()  # E: Cannot instantiate `Shape` because it has unimplemented abstract methods: `area`, `perimeter`
+Square()  # E: Cannot instantiate `Square` because it has unimplemented abstract methods: `perimeter`
+UnitSquare()
+def f(cls: type[Shape]) -> Shape:
+    return cls()
+    "#,
+);
+
+testcase!(
+    test_instantiate_abstract_class_metaclass,
+    r#"
+import abc
+class Meta(abc.ABCMeta): ...
+class A(metaclass=abc.ABCMeta):
+    @abc.abstractmethod
+    def f(self) -> None: ...
+class B(metaclass=Meta):
+    @abc.abstractmethod
+    def f(self) -> None: ...
+A()  # E: Cannot instantiate `A` because it has unimplemented abstract methods: `f`
+B()  # E: Cannot instantiate `B` because it has unimplemented abstract methods: `f`
+    "#,
+);
+
+testcase!(
+    test_instantiate_abstract_methods_without_abc_meta,
+    r#"
+from abc import abstractmethod
+class A:
+    @abstractmethod
+    def f(self) -> None: ...
+A()
+    "#,
+);
+
+#[test]
+fn test_is_abstract_class() {
+    let results = solve_in_main(
+        r#"
+from abc import ABC, abstractmethod
+class Shape(ABC):
+    @abstractmethod
+    def area(self) -> float: ...
+class Square(Shape):
+    def area(self) -> float:
+        return 1.0
+class NoMeta:
+    @abstractmethod
+    def area(self) -> float: ...
+"#,
+        |solver| {
+            ["Shape", "Square", "NoMeta"]
+                .map(|name| solver.is_abstract_class(&get_export_class(solver, name)))
+        },
+    );
+    assert_eq!(results, [true, false, false]);
+}
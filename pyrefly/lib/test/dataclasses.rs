@@ -546,3 +546,105 @@ class C(B):
         pass
     "#,
 );
+
+testcase!(
+    test_dataclass_transform_frozen_default,
+    r#"
+from typing import dataclass_transform
+
+@dataclass_transform(frozen_default=True)
+class ModelMeta(type): ...
+
+class BaseModel(metaclass=ModelMeta): ...
+
+class User(BaseModel):
+    name: str
+
+class MutableUser(BaseModel, frozen=False):
+    name: str
+
+def f(user: User, mutable: MutableUser):
+    User(name="x")  # OK
+    user.name = "y"  # E: Cannot assign to read-only field `name`
+    mutable.name = "y"  # OK
+    "#,
+);
+
+testcase!(
+    test_dataclass_transform_base_class,
+    r#"
+from typing import dataclass_transform
+
+@dataclass_transform(frozen_default=True)
+class ModelBase: ...
+
+class Point(ModelBase):
+    x: int
+
+class Point3D(Point):
+    z: int
+
+def f(p: Point3D):
+    Point3D(1, 2)  # OK
+    p.z = 0  # E: Cannot assign to read-only field `z`
+    "#,
+);
+
+testcase!(
+    test_dataclass_transform_kw_only_default,
+    r#"
+from typing import dataclass_transform
+
+@dataclass_transform(kw_only_default=True)
+def model[T](cls: type[T]) -> type[T]: ...
+
+@model
+class C:
+    x: int
+
+C(x=0)  # OK
+C(0)  # E: Missing argument `x`  # E: Expected 0 positional arguments
+    "#,
+);
+
+testcase!(
+    test_init_var,
+    r#"
+from dataclasses import dataclass, InitVar
+@dataclass
+class C:
+    x: int
+    y: InitVar[str]
+C(1, "a")  # OK
+C(1, 2)  # E: Argument `Literal[2]` is not assignable to parameter `y` with type `str`
+    "#,
+);
+
+testcase!(
+    test_post_init_matches_init_vars,
+    r#"
+from dataclasses import dataclass, InitVar
+@dataclass
+class Ok:
+    x: int
+    y: InitVar[str]
+    z: InitVar[int]
+    def __post_init__(self, y: str, z: int) -> None: ...
+
+@dataclass
+class Missing:
+    y: InitVar[str]
+    z: InitVar[int]
+    def __post_init__(self, y: str) -> None: ...  # E: `__post_init__` is missing a parameter for InitVar `z`
+
+@dataclass
+class Extra:
+    y: InitVar[str]
+    def __post_init__(self, y: str, w: int) -> None: ...  # E: `__post_init__` parameter `w` does not correspond to any InitVar
+
+@dataclass
+class Mistyped:
+    y: InitVar[str]
+    def __post_init__(self, y: int) -> None: ...  # E: `__post_init__` parameter for InitVar `y` has type `int`, which does not accept `str`
+    "#,
+);
@@ -5,6 +5,11 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::alt::expr::DecoratorEffect;
+use crate::binding::binding::Binding;
+use crate::binding::binding::Key;
+use crate::test::util::get_export;
+use crate::test::util::solve_in_main;
 use crate::testcase;
 
 testcase!(
@@ -309,3 +314,112 @@ def g(a: A):
 g(f)
     "#,
 );
+
+testcase!(
+    test_logging_decorator_paramspec,
+    r#"
+import functools
+from typing import Callable, ParamSpec, TypeVar, assert_type
+P = ParamSpec("P")
+R = TypeVar("R")
+def logged(fn: Callable[P, R]) -> Callable[P, R]:
+    @functools.wraps(fn)
+    def wrapper(*args: P.args, **kwargs: P.kwargs) -> R:
+        print("calling")
+        return fn(*args, **kwargs)
+    return wrapper
+@logged
+def add(x: int, y: int) -> int:
+    return x + y
+assert_type(add(1, 2), int)
+add(1, "2")  # E: is not assignable to parameter `y` with type `int`
+    "#,
+);
+
+testcase!(
+    test_logging_decorator_untyped_wraps,
+    r#"
+import functools
+from typing import assert_type
+def logged(fn):
+    @functools.wraps(fn)
+    def wrapper(*args, **kwargs):
+        print("calling")
+        return fn(*args, **kwargs)
+    return wrapper
+@logged
+def add(x: int, y: int) -> int:
+    return x + y
+assert_type(add(1, 2), int)
+add(1)  # E: Missing argument `y`
+    "#,
+);
+
+testcase!(
+    test_typed_wraps_wrapper_keeps_its_own_signature,
+    r#"
+import functools
+from typing import Callable, assert_type
+def stringify(fn: Callable[[int], int]):
+    @functools.wraps(fn)
+    def wrapper(x: int) -> str:
+        return str(fn(x))
+    return wrapper
+@stringify
+def double(x: int) -> int:
+    return x * 2
+assert_type(double(1), str)
+    "#,
+);
+
+#[test]
+fn test_resolve_decorator_effect() {
+    let effects = solve_in_main(
+        r#"
+from typing import Any, Callable, final
+def keep[F](f: F) -> F: ...
+def to_int(f: Callable[..., Any]) -> int: ...
+def f(x: int) -> int: ...
+class C:
+    @staticmethod
+    def a(): ...
+    @classmethod
+    def b(cls): ...
+    @property
+    def c(self): ...
+    @final
+    def d(self): ...
+    @keep
+    def e(self): ...
+    @to_int
+    def g(self): ...
+"#,
+        |solver| {
+            let bindings = solver.bindings();
+            let decoratee = get_export(solver, "f");
+            bindings
+                .keys::<Key>()
+                .filter(|idx| matches!(bindings.get(*idx), Binding::Decorator(_)))
+                .map(
+                    |idx| match solver.resolve_decorator_effect(idx, &decoratee) {
+                        DecoratorEffect::SignatureChanging(ty) => {
+                            format!("SignatureChanging({ty})")
+                        }
+                        effect => format!("{effect:?}"),
+                    },
+                )
+                .collect::<Vec<_>>()
+        },
+    );
+    assert_eq!(
+        effects,
+        [
+            "StaticMethod",
+            "ClassMethod",
+            "Property",
+            "Identity",
+            "Identity",
+            "SignatureChanging(int)",
+        ]
+    );
+}
@@ -240,3 +240,38 @@ def f(a: A):
     assert_type(A.h(), Coroutine[Any, Any, int])
     "#,
 );
+
+testcase!(
+    test_property_and_annotation_mismatch,
+    r#"
+class A:
+    x: int
+    @property
+    def x(self) -> str:  # E: Property `x` returns `str`, but `x` is annotated with `int` on line 3
+        return ""
+
+class B:
+    y: int
+    @property
+    def y(self) -> bool:
+        return True
+
+class C:
+    z: int
+    @property
+    def z(self) -> int:
+        return 0
+    @z.setter
+    def z(self, value: int) -> None:
+        pass
+
+class D:
+    w: int
+    @property
+    def w(self) -> str:  # E: Property `w` returns `str`, but `w` is annotated with `int` on line 24
+        return ""
+    @w.setter
+    def w(self, value: str) -> None:
+        pass
+    "#,
+);
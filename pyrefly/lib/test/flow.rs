@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::error::kind::ErrorKind;
 use crate::test::util::TestEnv;
 use crate::testcase;
 
@@ -580,6 +581,91 @@ except* Exception1 as e5:
 "#,
 );
 
+testcase!(
+    test_broad_except,
+    TestEnv::new_with_enabled_errors(&[ErrorKind::BroadExcept]),
+    r#"
+def f() -> None: ...
+
+try:
+    f()
+except BaseException:  # E: Catching `BaseException` also catches `KeyboardInterrupt` and `SystemExit`
+    pass
+
+try:
+    f()
+except (ValueError, BaseException):  # E: Catching `BaseException`
+    pass
+
+try:
+    f()
+except:  # E: Bare `except:` also catches `KeyboardInterrupt` and `SystemExit`
+    pass
+
+try:
+    f()
+except Exception:
+    pass
+
+try:
+    f()
+except KeyboardInterrupt:
+    pass
+
+try:
+    f()
+except BaseException:
+    print("cleaning up")
+    raise
+
+try:
+    f()
+except BaseException as e:
+    if str(e):
+        raise e
+
+try:
+    f()
+except:
+    raise
+"#,
+);
+
+testcase!(
+    test_broad_except_disabled_by_default,
+    r#"
+try:
+    pass
+except BaseException:
+    pass
+except:
+    pass
+"#,
+);
+
+testcase!(
+    test_shadowed_iterable,
+    TestEnv::new_with_enabled_errors(&[ErrorKind::ShadowedIterable]),
+    r#"
+def f(items: list[str], names: list[str]) -> None:
+    for items in items:  # E: Loop variable `items` shadows the iterable it loops over
+        print(items)
+    for names in names:
+        pass
+    for name in names:
+        print(name)
+"#,
+);
+
+testcase!(
+    test_shadowed_iterable_disabled_by_default,
+    r#"
+def f(items: list[str]) -> None:
+    for items in items:
+        print(items)
+"#,
+);
+
 testcase!(
     test_try_else,
     r#"
@@ -783,6 +869,25 @@ def fun(foo: Foo, bar: Bar, baz: Baz) -> None:
 "#,
 );
 
+testcase!(
+    test_match_class_keyword_missing_attribute,
+    r#"
+from typing import assert_type
+
+class Point:
+    x: int
+    y: int
+
+def fun(p: Point) -> None:
+    match p:
+        case Point(x=0, z=0):  # E: `Point` has no attribute `z`
+            pass
+        case Point(x=a, y=b):
+            assert_type(a, int)
+            assert_type(b, int)
+"#,
+);
+
 testcase!(
     test_match_sequence_concrete,
     r#"
@@ -1131,3 +1236,72 @@ if 0.1:
 assert_type(vari, Literal["test"])
 "#,
 );
+
+testcase!(
+    test_constant_condition,
+    TestEnv::new_with_enabled_errors(&[ErrorKind::ConstantCondition]),
+    r#"
+from typing import Literal
+def is_ready() -> bool: ...
+def f(x: int | None, y: tuple[int, str], z: Literal[0], n: None):
+    if is_ready:  # E: Condition is always true, since it has type `() -> bool`
+        pass
+    if y:  # E: Condition is always true, since it has type `tuple[int, str]`
+        pass
+    while z:  # E: Condition is always false, since it has type `Literal[0]`
+        pass
+    if n:  # E: Condition is always false, since it has type `None`
+        pass
+    if x:
+        pass
+    if is_ready():
+        pass
+    while True:
+        break
+    if 0:
+        pass
+"#,
+);
+
+testcase!(
+    test_constant_condition_disabled_by_default,
+    r#"
+def is_ready() -> bool: ...
+if is_ready:
+    pass
+"#,
+);
+
+testcase!(
+    test_unreachable_branch,
+    TestEnv::new_with_enabled_errors(&[ErrorKind::UnreachableBranch]),
+    r#"
+def get() -> tuple[int, str] | None: ...
+def f(x: tuple[int, str], n: None, b: bool):
+    if x:
+        pass
+    else:  # E: This branch is unreachable because the condition is always true, consider removing it
+        pass
+    if n:
+        pass  # E: This branch is unreachable because the condition is always false, consider removing it
+    elif b:
+        pass
+    if b:
+        pass
+    elif x:
+        pass
+    if (y := n):
+        pass  # E: This branch is unreachable because the condition is always false
+    if False:
+        pass
+"#,
+);
+
+testcase!(
+    test_unreachable_branch_disabled_by_default,
+    r#"
+def f(n: None):
+    if n:
+        pass
+"#,
+);
@@ -6,6 +6,8 @@
  */
 
 use crate::test::util::TestEnv;
+use crate::test::util::get_export_class;
+use crate::test::util::solve_in_main;
 use crate::testcase;
 
 testcase!(
@@ -824,3 +826,42 @@ class C4(Generic[int]):  # E: Expected a type variable, got `int`
     pass
     "#,
 );
+
+#[test]
+fn test_class_tparams() {
+    let results = solve_in_main(
+        r#"
+from typing import Generic, TypeVar
+T_co = TypeVar("T_co", covariant=True)
+U = TypeVar("U")
+class Legacy(Generic[T_co, U]): ...
+class Scoped[T, *Ts]: ...
+class NotGeneric: ...
+"#,
+        |solver| {
+            ["Legacy", "Scoped", "NotGeneric"].map(|name| {
+                let cls = get_export_class(solver, name);
+                let tparams = solver
+                    .class_tparams(&cls)
+                    .iter()
+                    .map(|tparam| format!("{} {}", tparam.name(), tparam.variance))
+                    .collect::<Vec<_>>();
+                (solver.class_is_generic(&cls), tparams)
+            })
+        },
+    );
+    assert_eq!(
+        results,
+        [
+            (
+                true,
+                vec!["T_co covariant".to_owned(), "U invariant".to_owned()]
+            ),
+            (
+                true,
+                vec!["T invariant".to_owned(), "Ts invariant".to_owned()]
+            ),
+            (false, Vec::new()),
+        ]
+    );
+}
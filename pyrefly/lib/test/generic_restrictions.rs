@@ -175,6 +175,29 @@ def func(c: T) -> C:
  "#,
 );
 
+testcase!(
+    test_protocol_bounded_typevar_method_call,
+    r#"
+from typing import Protocol, TypeVar, assert_type
+class SupportsClose(Protocol):
+    def close(self) -> int: ...
+class Resource:
+    def close(self) -> int:
+        return 0
+T = TypeVar('T', bound=SupportsClose)
+def close_all(xs: list[T]) -> list[T]:
+    for x in xs:
+        assert_type(x.close(), int)
+        x.open()  # E: Object of class `SupportsClose` has no attribute `open`
+    return xs
+def g[S: SupportsClose](x: S) -> S:
+    assert_type(x.close(), int)
+    return x
+assert_type(close_all([Resource()]), list[Resource])
+assert_type(g(Resource()), Resource)
+ "#,
+);
+
 testcase!(
     test_instantiate_default_typevar,
     r#"
@@ -559,6 +559,26 @@ fn test_import_fail_to_load() {
     assert_eq!(err.path().as_path().file_name(), Some("foo.py".as_ref()));
 }
 
+#[test]
+fn test_reveal_and_assert_type_in_stub() -> anyhow::Result<()> {
+    let env = TestEnv::one_with_path(
+        "foo",
+        "foo.pyi",
+        r#"
+from typing import assert_type, reveal_type
+def f(x: int) -> str: ...
+reveal_type(f(1))  # E: revealed type: str
+assert_type(f(1), str)
+assert_type(f(1), int)  # E: assert_type(str, int) failed
+"#,
+    );
+    let (state, handle) = env.to_state();
+    state
+        .transaction()
+        .get_errors([&handle("foo")])
+        .check_against_expectations()
+}
+
 testcase!(
     test_import_os,
     r#"
@@ -5,6 +5,8 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::error::kind::ErrorKind;
+use crate::test::util::TestEnv;
 use crate::testcase;
 
 testcase!(
@@ -170,6 +172,32 @@ bad: Literal[Literal, 3]  # E: Expected a type argument for `Literal`  # E: Inva
 "#,
 );
 
+testcase!(
+    test_literal_mixed_bool_and_enum,
+    r#"
+from enum import Enum
+from typing import Literal
+
+class Color(Enum):
+    RED = 1
+    BLUE = 2
+
+x: Literal[True, Color.RED, "a", 1, None] = Color.RED
+y: Literal[True, Color.RED] = True
+z: Literal[True, Color.RED] = Color.BLUE  # E: `Literal[Color.BLUE]` is not assignable to
+"#,
+);
+
+testcase!(
+    test_literal_float_and_complex,
+    r#"
+from typing import Literal
+a: Literal[3.14]  # E: `float` values are not allowed in `Literal`
+b: Literal[1, -2.5]  # E: `float` values are not allowed in `Literal`
+c: Literal[1j]  # E: `complex` values are not allowed in `Literal`
+"#,
+);
+
 testcase!(
     test_literal_brackets,
     r#"
@@ -211,3 +239,31 @@ i: int = 42
 assert_type(x[i], int)
 "#,
 );
+
+testcase!(
+    test_redundant_literal_in_union,
+    TestEnv::new_with_enabled_errors(&[ErrorKind::RedundantLiteral]),
+    r#"
+from enum import Enum
+from typing import Literal, Union
+
+class Color(Enum):
+    RED = 1
+
+a: int | Literal[3]  # E: `Literal[3]` is redundant because `int` already includes it, consider removing it
+b: Union[Literal["x"], str]  # E: `Literal['x']` is redundant because `str` already includes it
+c: Literal["a", "b", "a"]  # E: `Literal['a']` appears more than once in this union, consider removing the duplicate
+d: Color | Literal[Color.RED] | None  # E: `Literal[Color.RED]` is redundant because `Color` already includes it
+e: Literal[True, False] | str
+f: int | Literal["x"]
+"#,
+);
+
+testcase!(
+    test_redundant_literal_disabled_by_default,
+    r#"
+from typing import Literal
+a: int | Literal[3]
+b: Literal["a", "a"]
+"#,
+);
@@ -5,6 +5,8 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::test::util::get_export;
+use crate::test::util::solve_in_main;
 use crate::testcase;
 
 testcase!(
@@ -317,6 +319,35 @@ assert_type(x, str | None)
     "#,
 );
 
+testcase!(
+    test_while_is_not_none,
+    r#"
+from typing import assert_type
+def f() -> str | None: ...
+x = f()
+while x is not None:
+    assert_type(x, str)
+    x = f()
+assert_type(x, None)
+    "#,
+);
+
+testcase!(
+    test_while_is_not_none_break,
+    r#"
+from typing import assert_type
+def f() -> str | None: ...
+def g() -> bool: ...
+x = f()
+while x is not None:
+    assert_type(x, str)
+    if g():
+        break
+    x = f()
+assert_type(x, str | None)
+    "#,
+);
+
 testcase!(
     bug = "Unwanted EXPECTED error",
     test_while_overwrite,
@@ -412,6 +443,42 @@ if (x := f()) is None:
     "#,
 );
 
+testcase!(
+    test_walrus_is_not_none,
+    r#"
+from typing import assert_type
+def get() -> int | None: ...
+if (n := get()) is not None:
+    assert_type(n, int)
+else:
+    assert_type(n, None)
+    "#,
+);
+
+testcase!(
+    test_walrus_while,
+    r#"
+from typing import assert_type
+def get() -> str | None: ...
+def f():
+    while (n := get()) is not None:
+        assert_type(n, str)
+    "#,
+);
+
+testcase!(
+    test_walrus_while_rebinds_name,
+    r#"
+from typing import assert_type
+def get() -> str | None: ...
+def f():
+    n = 0
+    while (n := get()) is not None:
+        assert_type(n, str)
+        n = 1
+    "#,
+);
+
 testcase!(
     test_match_enum_fallback,
     r#"
@@ -494,6 +561,32 @@ def f(x: str | int | None):
     "#,
 );
 
+testcase!(
+    test_isinstance_exhausts_union,
+    r#"
+from typing import assert_never, assert_type, Never
+def f(x: int | str):
+    if not isinstance(x, int) and not isinstance(x, str):
+        assert_type(x, Never)
+        assert_never(x)
+    else:
+        assert_type(x, int | str)
+def g(x: int | str | None):
+    if isinstance(x, int):
+        pass
+    elif isinstance(x, str):
+        pass
+    elif x is None:
+        pass
+    else:
+        assert_type(x, Never)
+        assert_never(x)
+def h(x: int | str):
+    if not isinstance(x, int) and not isinstance(x, bytes):
+        assert_never(x)  # E: Argument `str` is not assignable to parameter
+    "#,
+);
+
 testcase!(
     test_isinstance_of_tuple,
     r#"
@@ -683,6 +776,33 @@ def f(x: Cat | Dog):
     "#,
 );
 
+testcase!(
+    test_typeguard_narrows_to_unrelated_type,
+    r#"
+from typing import TypeGuard, assert_type
+def is_str_list(val: list[object]) -> TypeGuard[list[str]]:
+    return all(isinstance(x, str) for x in val)
+def f(x: list[object]):
+    if is_str_list(x):
+        assert_type(x, list[str])
+    else:
+        assert_type(x, list[object])
+    "#,
+);
+
+testcase!(
+    test_typeis_negated_call,
+    r#"
+from typing import TypeIs, assert_type
+def is_int(val: int | str) -> TypeIs[int]: ...
+def f(x: int | str):
+    if not is_int(x):
+        assert_type(x, str)
+    else:
+        assert_type(x, int)
+    "#,
+);
+
 testcase!(
     test_typeis,
     r#"
@@ -722,6 +842,35 @@ def f(x:  A | B | C, y: A | C):
     "#,
 );
 
+// `callable` is declared in typeshed as returning `TypeIs[Callable[..., object]]`.
+testcase!(
+    test_callable,
+    r#"
+from typing import Callable, assert_type
+def f(x: int | Callable[[], None]):
+    if callable(x):
+        assert_type(x, Callable[[], None])
+    else:
+        assert_type(x, int)
+    "#,
+);
+
+testcase!(
+    test_callable_dunder_call,
+    r#"
+from typing import Callable, assert_type
+class Handler:
+    def __call__(self) -> None: ...
+def f(x: int | Handler | type[str]):
+    if callable(x):
+        assert_type(x, Handler | type[str])
+    else:
+        assert_type(x, int)
+    if not callable(x):
+        assert_type(x, int)
+    "#,
+);
+
 testcase!(
     test_issubclass,
     r#"
@@ -1155,3 +1304,34 @@ def test(x: tuple[int, int], y: tuple[int, *tuple[int, ...], int], z: tuple[int,
         assert_type(nt, NT)
 "#,
 );
+
+#[test]
+fn test_coerce_to_bool() {
+    let coerced = solve_in_main(
+        r#"
+def f() -> None: ...
+n: None
+t: tuple[int]
+e: tuple[()]
+i: int
+u: int | None
+"#,
+        |solver| {
+            ["f", "n", "t", "e", "i", "u"].map(|name| {
+                let ty = get_export(solver, name);
+                solver.coerce_to_bool(&ty).to_string()
+            })
+        },
+    );
+    assert_eq!(
+        coerced.each_ref().map(|x| x.as_str()),
+        [
+            "Literal[True]",
+            "Literal[False]",
+            "Literal[True]",
+            "Literal[False]",
+            "bool",
+            "bool",
+        ]
+    );
+}
@@ -5,6 +5,14 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use ruff_python_ast::name::Name;
+use ruff_text_size::TextRange;
+
+use crate::alt::callable::CallArg;
+use crate::error::kind::ErrorKind;
+use crate::test::util::TestEnv;
+use crate::test::util::get_export;
+use crate::test::util::solve_in_main;
 use crate::testcase;
 
 testcase!(
@@ -426,3 +434,112 @@ class C:
         return -self
     "#,
 );
+
+testcase!(
+    test_compare_to_class,
+    TestEnv::new_with_enabled_errors(&[ErrorKind::CompareToClass]),
+    r#"
+def f(x: int | str, y: type[int], z: None) -> None:
+    if x == int:  # E: Comparing a value of type `int | str` to the class `int` with `==`, did you mean `isinstance(...)`?
+        pass
+    if x != str:  # E: did you mean `not isinstance(...)`?
+        pass
+    if z == int:  # E: did you mean `isinstance(...)`?
+        pass
+    if y == int:
+        pass
+    if type(x) == int:
+        pass
+    if x is int:
+        pass
+    "#,
+);
+
+testcase!(
+    test_compare_to_class_custom_eq,
+    TestEnv::new_with_enabled_errors(&[ErrorKind::CompareToClass]),
+    r#"
+class Matcher:
+    def __eq__(self, other: type) -> bool:
+        return True
+class Plain:
+    def __eq__(self, other: object) -> bool:
+        return True
+def f(m: Matcher, p: Plain) -> None:
+    m == int
+    p == int  # E: did you mean `isinstance(...)`?
+    "#,
+);
+
+testcase!(
+    test_compare_to_class_disabled_by_default,
+    r#"
+def f(x: int) -> None:
+    if x == int:
+        pass
+    "#,
+);
+
+testcase!(
+    test_non_overlapping_container,
+    TestEnv::new_with_enabled_errors(&[ErrorKind::NonOverlappingContainer]),
+    r#"
+def f(xs: list[int], d: dict[str, int], x: int | str, o: object) -> None:
+    "x" in [1, 2, 3]  # E: `in` is always false because `Literal['x']` can never be an element of `list[int]`
+    "x" not in xs  # E: `not in` is always true
+    1 in d  # E: `in` is always false because `Literal[1]` can never be an element of `dict[str, int]`
+    "x" in (1, 2)  # E: `in` is always false
+    1 in xs
+    1 in [2.0]
+    x in xs
+    o in xs
+    "x" in d
+    "x" in "xyz"
+    b"x" in b"xyz"
+    "#,
+);
+
+testcase!(
+    test_non_overlapping_container_disabled_by_default,
+    r#"
+"x" in [1, 2, 3]
+    "#,
+);
+
+#[test]
+fn test_call_dunder() {
+    let results = solve_in_main(
+        r#"
+class A:
+    def __add__(self, other: int) -> str: ...
+a: A = A()
+n: int = 0
+"#,
+        |solver| {
+            let a = get_export(solver, "a");
+            let n = get_export(solver, "n");
+            let arg = CallArg::Type(&n, TextRange::default());
+            ["__add__", "__sub__"].map(|dunder| {
+                let errors = solver.error_collector();
+                let ty = solver.call_dunder(
+                    &a,
+                    &Name::new_static(dunder),
+                    &[arg.clone()],
+                    TextRange::default(),
+                    &errors,
+                );
+                (ty.to_string(), errors.first_message())
+            })
+        },
+    );
+    assert_eq!(
+        results,
+        [
+            ("str".to_owned(), None),
+            (
+                "Unknown".to_owned(),
+                Some("Type `A` has no magic method `__sub__`".to_owned())
+            ),
+        ]
+    );
+}
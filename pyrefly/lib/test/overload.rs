@@ -386,3 +386,80 @@ def g(x: int):
     f(C(x))
     "#,
 );
+
+testcase!(
+    test_no_matching_overload_lists_overloads,
+    r#"
+from typing import overload
+
+@overload
+def f(x: int) -> int: ...
+@overload
+def f(x: str, *, flag: bool) -> str: ...
+def f(x: int | str, flag: bool = False) -> int | str: ...
+
+f(1, True)  # E: closest overload: `(x: int) -> int`\n  Overload `(x: int) -> int`: Expected 1 positional argument, got 2\n  Overload `(x: str, *, flag: bool) -> str`: Expected 1 positional argument, got 2  # E: Expected 1 positional argument, got 2
+    "#,
+);
+
+testcase!(
+    test_no_matching_overload_caps_listed_overloads,
+    r#"
+from typing import overload
+
+@overload
+def f(x: int) -> int: ...
+@overload
+def f(x: str) -> str: ...
+@overload
+def f(x: bytes) -> bytes: ...
+@overload
+def f(x: float) -> float: ...
+@overload
+def f(x: complex) -> complex: ...
+@overload
+def f(x: list[int]) -> list[int]: ...
+def f(x: object) -> object: ...
+
+f(1, 2)  # E: Overload `(x: float) -> float`: Expected 1 positional argument, got 2\n  ...and 2 more  # E: Expected 1 positional argument, got 2
+    "#,
+);
+
+testcase!(
+    test_unreachable_overload,
+    r#"
+from typing import Any, overload
+
+@overload
+def f(x: int) -> int: ...
+@overload
+def f(x: bool) -> bool: ...  # E: Overload is unreachable because the earlier overload at line 5 is more general
+def f(x: int) -> int: ...
+
+@overload
+def g(x: int, y: int = 0) -> int: ...
+@overload
+def g(x: int) -> str: ...  # E: Overload is unreachable because the earlier overload at line 11 is more general
+def g(x: int, y: int = 0) -> int | str: ...
+
+@overload
+def h(x: bool) -> bool: ...
+@overload
+def h(x: int) -> int: ...
+def h(x: int) -> int: ...
+
+@overload
+def i(x: int) -> int: ...
+@overload
+def i(x: Any) -> str: ...
+def i(x: Any) -> int | str: ...
+
+@overload
+def j(x: int) -> int: ...
+@overload
+def j(x: str) -> str: ...
+@overload
+def j(x: bool) -> bool: ...  # E: Overload is unreachable because the earlier overload at line 29 is more general
+def j(x: int | str) -> int | str: ...
+    "#,
+);
@@ -1,4 +1,8 @@
+use crate::test::util::get_export;
+use crate::test::util::solve_in_main;
 use crate::testcase;
+use crate::types::literal::Lit;
+use crate::types::types::Type;
 
 testcase!(
     test_double_name_match,
@@ -12,3 +16,45 @@ match 42:
 print(y)
     "#,
 );
+
+#[test]
+fn test_remaining_after_patterns() {
+    let (remaining, exhaustive) = solve_in_main(
+        r#"
+from enum import Enum
+class Color(Enum):
+    RED = 1
+    GREEN = 2
+c: Color
+b: bool
+red = Color.RED
+green = Color.GREEN
+"#,
+        |solver| {
+            let [c, b, red, green] = ["c", "b", "red", "green"].map(|x| get_export(solver, x));
+            let Type::ClassType(color) = &c else {
+                unreachable!()
+            };
+            let yes = Type::Literal(Lit::Bool(true));
+            let no = Type::Literal(Lit::Bool(false));
+            (
+                [
+                    solver.remaining_after_patterns(&c, &[red.clone()]),
+                    solver.remaining_after_patterns(&c, &[red.clone(), green.clone()]),
+                    solver.remaining_after_patterns(&b, &[yes.clone()]),
+                    solver.remaining_after_patterns(&b, &[yes, no]),
+                ]
+                .map(|t| t.to_string()),
+                [
+                    solver.enum_is_exhaustive(color, &[green.clone()]),
+                    solver.enum_is_exhaustive(color, &[green, red]),
+                ],
+            )
+        },
+    );
+    assert_eq!(
+        remaining.each_ref().map(|x| x.as_str()),
+        ["Literal[Color.GREEN]", "Never", "Literal[False]", "Never"]
+    );
+    assert_eq!(exhaustive, [false, true]);
+}
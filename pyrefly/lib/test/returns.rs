@@ -5,6 +5,8 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::error::kind::ErrorKind;
+use crate::test::util::TestEnv;
 use crate::testcase;
 
 testcase!(
@@ -270,3 +272,50 @@ assert_type(async_f_annotated, Callable[[], Coroutine[Any, Any, int]])
 assert_type(async_f_inferred, Callable[[], Coroutine[Any, Any, int]])
      "#,
 );
+
+testcase!(
+    test_inconsistent_returns,
+    TestEnv::new_with_enabled_errors(&[ErrorKind::InconsistentReturns]),
+    r#"
+class A: ...
+class B(A): ...
+class C(A): ...
+
+def f(x: bool):  # E: Function returns both `int` and `str`, which have no common base other than `object`
+    if x:
+        return 1
+    return "no"
+
+def g(x: bool):
+    if x:
+        return B()
+    return C()
+
+def h(x: bool):
+    if x:
+        return 1
+    elif x:
+        return True
+    return None
+
+def i(x: bool, y: int | str):
+    if x:
+        return 1
+    return y
+
+def j(x: bool):
+    if x:
+        return []
+    return [1]
+"#,
+);
+
+testcase!(
+    test_inconsistent_returns_disabled_by_default,
+    r#"
+def f(x: bool):
+    if x:
+        return 1
+    return "no"
+"#,
+);
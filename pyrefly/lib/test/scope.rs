@@ -55,7 +55,17 @@ testcase!(
     test_global_not_found,
     r#"
 x: str = ""
-global a  # E: Could not find name `a`
+global a  # E: No binding for `global a` found at module level
+"#,
+);
+
+testcase!(
+    test_global_not_found_in_function,
+    r#"
+x: str = ""
+def f() -> None:
+    global a  # E: No binding for `global a` found at module level
+    a = "foo"
 "#,
 );
 
@@ -300,6 +310,24 @@ def f() -> None:
 "#,
 );
 
+testcase!(
+    test_nonlocal_no_binding,
+    r#"
+def f() -> None:
+    def g() -> None:
+        nonlocal a  # E: No binding for `nonlocal a` found in an enclosing scope
+        a = "foo"
+"#,
+);
+
+testcase!(
+    test_nonlocal_at_module_level,
+    r#"
+x: str = ""
+nonlocal x  # E: `nonlocal` declaration is not allowed at module level
+"#,
+);
+
 testcase!(
     test_del_name,
     r#"
@@ -5,7 +5,10 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::error::kind::ErrorKind;
 use crate::test::util::TestEnv;
+use crate::test::util::get_export;
+use crate::test::util::solve_in_main;
 use crate::testcase;
 
 testcase!(
@@ -385,6 +388,25 @@ y = "bar"  # E: `y` is marked final
 "#,
 );
 
+testcase!(
+    test_del_final,
+    r#"
+from typing import Final
+
+x: Final = 0
+del x  # E: Cannot delete Final variable `x`
+
+class A:
+    X: Final[int] = 1
+    y: int = 2
+
+del A.X  # E: Cannot delete Final attribute `X`
+a = A()
+del a.X  # E: Cannot delete Final attribute `X`
+del a.y
+"#,
+);
+
 testcase!(
     test_solver_variables,
     r#"
@@ -631,6 +653,38 @@ d2: dict[str, int] = {**d1}
 "#,
 );
 
+testcase!(
+    test_unhashable_keys_and_elements,
+    r#"
+from dataclasses import dataclass
+
+class A:
+    __hash__ = None
+
+class B(A): ...
+
+class C:
+    pass
+
+@dataclass
+class D:
+    x: int
+
+{A(): 1}  # E: `A` is unhashable and cannot be used as a dict key
+{B()}  # E: `B` is unhashable and cannot be used as a set element
+{C(): 1, 2: C()}
+{D(1)}  # E: `D` is unhashable and cannot be used as a set element
+{[1]: 2}  # E: `list[int]` is unhashable and cannot be used as a dict key
+{x for x in [A()]}  # E: `A` is unhashable and cannot be used as a set element
+{A(): x for x in [1]}  # E: `A` is unhashable and cannot be used as a dict key
+
+def f(d: dict[A, int], m: dict[list[int], int]) -> None:
+    d[A()]  # E: `A` is unhashable and cannot be used as a mapping key
+    m[[1]]  # E: `list[int]` is unhashable and cannot be used as a mapping key
+    d[B()]  # E: `B` is unhashable and cannot be used as a mapping key
+"#,
+);
+
 testcase!(
     test_iterable,
     r#"
@@ -735,7 +789,19 @@ class A:
     def __iter__(self) -> None:
         return None
 def f(x: A):
-    for _ in x:  # E: Type `A` is not iterable
+    for _ in x:  # E: Type `A` is not iterable\n  `__iter__` must return an iterator (an object with `__next__`), got `None`
+        pass
+    "#,
+);
+
+testcase!(
+    test_iterable_dunder_iter_returns_int,
+    r#"
+class A:
+    def __iter__(self) -> int:
+        return 0
+def f(x: A):
+    for _ in x:  # E: `__iter__` must return an iterator (an object with `__next__`), got `int`
         pass
     "#,
 );
@@ -1089,6 +1155,32 @@ cast(1, 1)  # E: First argument to `typing.cast` must be a type
     "#,
 );
 
+testcase!(
+    test_redundant_cast,
+    TestEnv::new_with_enabled_errors(&[ErrorKind::RedundantCast]),
+    r#"
+from typing import Any, cast
+import typing
+
+def f(x: object) -> None:
+    cast(Any, x)  # E: Cast to `Any` is unnecessary, use the value directly
+    cast(int, cast(str, x))  # E: Cast of the result of another `cast`, only the outer cast has any effect
+    cast(int, typing.cast(str, x))  # E: only the outer cast has any effect
+    cast(int, x)
+    cast(list[Any], x)
+    "#,
+);
+
+testcase!(
+    test_redundant_cast_disabled_by_default,
+    r#"
+from typing import Any, cast
+def f(x: object) -> None:
+    cast(Any, x)
+    cast(int, cast(str, x))
+    "#,
+);
+
 testcase!(
     test_special_calls_unexpected_keyword,
     r#"
@@ -1435,3 +1527,26 @@ def g(f: Callable[[Any], int], inputs: Any) -> None:
     sum(map(f, inputs))
     "#,
 );
+
+#[test]
+fn test_unwrap_type_type() {
+    let unwrapped = solve_in_main(
+        r#"
+t: type[int] = int
+A = str
+type B = int
+x: int = 0
+"#,
+        |solver| {
+            ["t", "A", "B", "x"].map(|name| {
+                solver
+                    .unwrap_type_type(&get_export(solver, name))
+                    .map(|ty| ty.to_string())
+            })
+        },
+    );
+    assert_eq!(
+        unwrapped.each_ref().map(|x| x.as_deref()),
+        [Some("int"), Some("str"), None, None]
+    );
+}
@@ -15,8 +15,10 @@ use dupe::Dupe;
 use starlark_map::small_map::SmallMap;
 
 use crate::config::config::ConfigFile;
+use crate::config::error::ErrorDisplayConfig;
 use crate::config::finder::ConfigFinder;
 use crate::error::error::print_errors;
+use crate::error::kind::ErrorKind;
 use crate::module::module_name::ModuleName;
 use crate::module::module_path::ModulePath;
 use crate::state::handle::Handle;
@@ -78,25 +80,17 @@ else:
         .unwrap();
 }
 
-#[test]
-fn test_multiple_path() {
-    const LIB_PYI: &str = "x: int";
-    const LIB_PY: &str = "x: str = 1  # E: `Literal[1]` is not assignable to `str`";
-    const MAIN_PYI: &str =
-        "import lib; y: list[int] = lib.x  # E: `int` is not assignable to `list[int]`";
-    const MAIN_PY: &str =
-        "import lib; y: list[str] = lib.x  # E: `int` is not assignable to `list[str]`";
-
-    const FILES: &[(&str, &str, &str)] = &[
-        ("lib", "lib.pyi", LIB_PYI),
-        ("lib", "lib.py", LIB_PY),
-        ("main", "main.pyi", MAIN_PYI),
-        ("main", "main.py", MAIN_PY),
-    ];
-
+/// Check the given `(module, path, contents)` files against their `# E:` expectations,
+/// with the given off-by-default error kinds enabled, returning the number of errors shown.
+fn check_files(files: &[(&str, &str, &str)], enabled_errors: &[ErrorKind]) -> usize {
     let mut config = ConfigFile::default();
     config.python_environment.set_empty_to_default();
-    for (name, path, _) in FILES.iter().rev() {
+    if !enabled_errors.is_empty() {
+        config.root.errors = Some(ErrorDisplayConfig::new(
+            enabled_errors.iter().map(|kind| (*kind, true)).collect(),
+        ));
+    }
+    for (name, path, _) in files.iter().rev() {
         config.custom_module_paths.insert(
             ModuleName::from_str(name),
             ModulePath::memory(PathBuf::from(path)),
@@ -108,7 +102,7 @@ fn test_multiple_path() {
     let sys_info = SysInfo::default();
 
     let state = State::new(ConfigFinder::new_constant(config));
-    let handles = FILES.map(|(name, path, _)| {
+    let handles = files.map(|(name, path, _)| {
         Handle::new(
             ModuleName::from_str(name),
             ModulePath::memory(PathBuf::from(path)),
@@ -117,7 +111,7 @@ fn test_multiple_path() {
     });
     let mut transaction = state.new_transaction(Require::Exports, None);
     transaction.set_memory(
-        FILES.map(|(_, path, contents)| {
+        files.map(|(_, path, contents)| {
             (PathBuf::from(path), Some(Arc::new((*contents).to_owned())))
         }),
     );
@@ -125,7 +119,77 @@ fn test_multiple_path() {
     let loads = transaction.get_errors(handles.iter());
     print_errors(&loads.collect_errors().shown);
     loads.check_against_expectations().unwrap();
-    assert_eq!(loads.collect_errors().shown.len(), 3);
+    loads.collect_errors().shown.len()
+}
+
+#[test]
+fn test_multiple_path() {
+    const LIB_PYI: &str = "x: int";
+    const LIB_PY: &str = "x: str = 1  # E: `Literal[1]` is not assignable to `str`";
+    const MAIN_PYI: &str =
+        "import lib; y: list[int] = lib.x  # E: `int` is not assignable to `list[int]`";
+    const MAIN_PY: &str =
+        "import lib; y: list[str] = lib.x  # E: `int` is not assignable to `list[str]`";
+
+    const FILES: &[(&str, &str, &str)] = &[
+        ("lib", "lib.pyi", LIB_PYI),
+        ("lib", "lib.py", LIB_PY),
+        ("main", "main.pyi", MAIN_PYI),
+        ("main", "main.py", MAIN_PY),
+    ];
+
+    assert_eq!(check_files(FILES, &[]), 3);
+}
+
+#[test]
+fn test_stub_mismatch() {
+    const LIB_PYI: &str = r#"
+def f(x: int) -> int: ...
+def g(x: int) -> int: ...
+def h(x: int) -> int: ...
+class C:
+    x: int
+    def m(self, y: int) -> int: ...
+    def n(self) -> C: ...
+class D:
+    def m(self) -> int: ...
+class E:
+    def m(self) -> int: ...
+"#;
+    const LIB_PY: &str = r#"
+def f(x: int) -> int:
+    return x
+def g(x: str) -> int:  # E: `g` has type `(x: str) -> int`, which is not consistent with its declaration `(x: int) -> int` in `lib.pyi`
+    return 0
+def h(x: int):
+    return x
+def internal(x: str) -> str:
+    return x
+class C:
+    def __init__(self) -> None:
+        self.x = 0
+    def m(self, y: int) -> int:
+        return y
+    def n(self) -> C:
+        return self
+class D:  # E: `D.m` has type `(self: D) -> str`, which is not consistent with its declaration `(self: D) -> int` in `lib.pyi`
+    def m(self) -> str:
+        return ""
+class E:  # E: `E.m` is declared in `lib.pyi` but not defined
+    pass
+"#;
+    const MAIN_PY: &str = r#"
+import lib
+lib.f(1)
+"#;
+
+    const FILES: &[(&str, &str, &str)] = &[
+        ("lib", "lib.pyi", LIB_PYI),
+        ("lib", "lib.py", LIB_PY),
+        ("main", "main.py", MAIN_PY),
+    ];
+
+    assert_eq!(check_files(FILES, &[ErrorKind::StubMismatch]), 3);
 }
 
 #[derive(Default, Clone, Dupe, Debug)]
@@ -448,3 +512,25 @@ fn test_error_clearing_on_dependency() {
         "Expected errors after fixing the dependency"
     );
 }
+
+#[test]
+fn test_diagnostic_count_by_kind() {
+    let mut env = TestEnv::new();
+    env.add(
+        "main",
+        r#"
+x: int = "1"
+y: str = 2
+z: int = "3"  # type: ignore
+import does_not_exist
+"#,
+    );
+    let (state, handle) = env.to_state();
+    let counts = state
+        .transaction()
+        .get_errors([&handle("main")])
+        .diagnostic_count_by_kind();
+    assert_eq!(counts.get(&ErrorKind::BadAssignment), Some(&2));
+    assert_eq!(counts.get(&ErrorKind::ImportError), Some(&1));
+    assert_eq!(counts.len(), 2);
+}
@@ -5,6 +5,10 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use ruff_text_size::TextRange;
+
+use crate::test::util::get_export;
+use crate::test::util::solve_in_main;
 use crate::testcase;
 
 testcase!(
@@ -360,3 +364,62 @@ def g(x):
         assert_type(x, tuple)
 "#,
 );
+
+#[test]
+fn test_subscript_result_type() {
+    let results = solve_in_main(
+        r#"
+from typing import Literal, NamedTuple, TypedDict
+class P(NamedTuple):
+    x: int
+    y: str
+class TD(TypedDict):
+    k: bytes
+t: tuple[int, str] = (1, "")
+p: P = P(1, "")
+td: TD = {"k": b""}
+l: list[float] = []
+zero: Literal[0] = 0
+minus_one: Literal[-1] = -1
+two: Literal[2] = 2
+key: Literal["k"] = "k"
+missing: Literal["m"] = "m"
+n: int = 0
+"#,
+        |solver| {
+            let get = |name| get_export(solver, name);
+            [
+                ("t", "zero"),
+                ("t", "minus_one"),
+                ("t", "two"),
+                ("p", "minus_one"),
+                ("td", "key"),
+                ("td", "missing"),
+                ("l", "n"),
+            ]
+            .map(|(base, index)| {
+                let errors = solver.error_collector();
+                let ty = solver.subscript_result_type(
+                    &get(base),
+                    &get(index),
+                    TextRange::default(),
+                    &errors,
+                );
+                (ty.to_string(), errors.first_message())
+            })
+        },
+    );
+    let unknown = |msg: &str| ("Unknown".to_owned(), Some(msg.to_owned()));
+    assert_eq!(
+        results,
+        [
+            ("int".to_owned(), None),
+            ("str".to_owned(), None),
+            unknown("Index 2 out of range for tuple with 2 elements"),
+            ("str".to_owned(), None),
+            ("bytes".to_owned(), None),
+            unknown("TypedDict `TD` does not have key `m`"),
+            ("float".to_owned(), None),
+        ]
+    );
+}
@@ -6,6 +6,8 @@
  */
 
 use crate::test::util::TestEnv;
+use crate::test::util::get_export;
+use crate::test::util::solve_in_main;
 use crate::testcase;
 
 testcase!(
@@ -222,11 +224,26 @@ testcase!(
     r#"
 from typing import Any, assert_type
 type X[T1, T2] = dict[T1, T2]
-def f(x: X[int]):  # E: Expected 2 type arguments for `X`, got 1
+def f(x: X[int]):  # E: Expected 2 type arguments for type alias `X`, got 1
     assert_type(x, dict[int, Any])
     "#,
 );
 
+testcase!(
+    test_generic_alias_wrong_targ_count,
+    r#"
+from typing import Any, assert_type
+type X[T1, T2 = int] = dict[T1, T2]
+type Y[T = str] = list[T]
+def f(x: X[int, str, bytes], y: Y):  # E: Expected 2 type arguments for type alias `X`, got 3
+    assert_type(y, list[str])
+def g(x: X):  # E: Expected 2 type arguments for type alias `X`, got 0
+    pass
+def h(x: X[bytes]):
+    assert_type(x, dict[bytes, int])
+    "#,
+);
+
 testcase!(
     test_generic_alias_annotated,
     r#"
@@ -555,3 +572,55 @@ foo = get_class() # Cannot be an alias because of syntax
 bar: type[object] = get_class()
 "#,
 );
+
+#[test]
+fn test_type_alias_arity() {
+    let arities = solve_in_main(
+        r#"
+from typing import TypeAlias
+type A[T1, T2 = int] = dict[T1, T2]
+type B = int
+C: TypeAlias = str
+x: int = 0
+"#,
+        |solver| {
+            ["A", "B", "C", "x"].map(|name| solver.type_alias_arity(&get_export(solver, name)))
+        },
+    );
+    assert_eq!(arities, [Some((2, 1)), Some((0, 0)), Some((0, 0)), None]);
+}
+
+#[test]
+fn test_normalize_for_display() {
+    let displayed = solve_in_main(
+        r#"
+A = int
+x: int | str = 0
+"#,
+        |solver| {
+            ["A", "x"].map(|name| {
+                let ty = get_export(solver, name);
+                solver.normalize_for_display(ty).to_string()
+            })
+        },
+    );
+    assert_eq!(displayed, ["type[int]", "int | str"]);
+}
+
+#[test]
+fn test_expand_aliases() {
+    let expanded = solve_in_main(
+        r#"
+type B = int
+type L = list[B]
+x: int = 0
+"#,
+        |solver| {
+            ["B", "L", "x"].map(|name| {
+                let ty = get_export(solver, name);
+                solver.expand_aliases(ty).to_string()
+            })
+        },
+    );
+    assert_eq!(expanded, ["int", "list[int]", "int"]);
+}
@@ -147,3 +147,17 @@ class A:
     def f(self, *args: Unpack[_Ts]): ...
 "#,
 );
+
+testcase!(
+    test_type_var_tuple_in_callable,
+    r#"
+from typing import Callable, TypeVarTuple, Unpack
+
+Ts = TypeVarTuple("Ts")
+def f(x: Callable[[int, *Ts], None], y: Callable[[*Ts, str], int]): ...
+def g(x: Callable[[int, Unpack[Ts]], None]): ...
+def h(x: Callable[[*Ts, *Ts], None]): ...  # E: Only one unbounded type is allowed to be unpacked
+def i(x: Callable[[int, *Ts, *tuple[str, ...]], None]): ...  # E: Only one unbounded type is allowed to be unpacked
+def j(x: Callable[[Ts], None]): ...  # E: TypeVarTuple must be unpacked
+"#,
+);
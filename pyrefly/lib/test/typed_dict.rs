@@ -5,7 +5,10 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::test::util::get_export;
+use crate::test::util::solve_in_main;
 use crate::testcase;
+use crate::types::types::Type;
 
 testcase!(
     test_typed_dict,
@@ -559,3 +562,56 @@ def f(c: C):
     c.setdefault("x", default=0)  # E: No matching overload  # E: Expected 1 more positional argument  # E: Unexpected keyword argument
     "#,
 );
+
+#[test]
+fn test_is_typed_dict() {
+    let results = solve_in_main(
+        r#"
+from typing import TypedDict
+class Coord(TypedDict):
+    x: int
+c: Coord = {"x": 0}
+d: dict[str, int] = {"x": 0}
+"#,
+        |solver| ["c", "d", "Coord"].map(|name| solver.is_typed_dict(&get_export(solver, name))),
+    );
+    assert_eq!(results, [true, false, false]);
+}
+
+#[test]
+fn test_typed_dict_field_list() {
+    let fields = solve_in_main(
+        r#"
+from typing import NotRequired, ReadOnly, TypedDict
+class Base(TypedDict):
+    x: int
+    y: NotRequired[str]
+class Child(Base, total=False):
+    z: ReadOnly[bytes]
+c: Child = {"x": 0}
+"#,
+        |solver| match get_export(solver, "c") {
+            Type::TypedDict(td) => solver
+                .typed_dict_field_list(&td)
+                .into_iter()
+                .map(|(name, field)| {
+                    (
+                        name.to_string(),
+                        field.ty.to_string(),
+                        field.required,
+                        field.read_only,
+                    )
+                })
+                .collect::<Vec<_>>(),
+            _ => unreachable!(),
+        },
+    );
+    assert_eq!(
+        fields,
+        [
+            ("x".to_owned(), "int".to_owned(), true, false),
+            ("y".to_owned(), "str".to_owned(), false, false),
+            ("z".to_owned(), "bytes".to_owned(), false, true),
+        ]
+    );
+}
@@ -81,7 +81,6 @@ class A:
 );
 
 testcase!(
-    bug = "Should not contain any errors",
     test_instance_attr,
     r#"
 from typing import Self, assert_type
@@ -91,8 +90,25 @@ class A:
         assert_type(self.x, Self)
 class B(A):
     pass
-assert_type(A().x, A)  # E:
-assert_type(B().x, B)  # E:
+assert_type(A().x, A)
+assert_type(B().x, B)
+    "#,
+);
+
+testcase!(
+    test_instance_attr_nested_self,
+    r#"
+from typing import Self, assert_type
+class Node:
+    children: list[Self]
+    def f(self):
+        assert_type(self.children, list[Self])
+class TreeNode(Node):
+    pass
+def f(node: Node, tree: TreeNode):
+    assert_type(node.children, list[Node])
+    assert_type(tree.children, list[TreeNode])
+    assert_type(tree.children[0].children, list[TreeNode])
     "#,
 );
 
@@ -181,6 +181,28 @@ assert_type(c.f(), Any)
 "#,
 );
 
+testcase!(
+    test_param_defaults_with_mode_check_and_infer_return_any,
+    TestEnv::new_with_untyped_def_behavior(UntypedDefBehavior::CheckAndInferReturnAny),
+    r#"
+from typing import assert_type, Any
+def f(x=0, y="y"):
+    assert_type(x, Any)
+    assert_type(y, Any)
+f("x", 1)
+"#,
+);
+
+testcase!(
+    test_param_defaults_with_mode_skip_and_infer_return_any,
+    TestEnv::new_with_untyped_def_behavior(UntypedDefBehavior::SkipAndInferReturnAny),
+    r#"
+def f(x=0, y="y"):
+    pass
+f("x", 1)
+"#,
+);
+
 testcase!(
     test_annotated_defs_with_mode_skip_and_infer_return_any,
     TestEnv::new_with_untyped_def_behavior(UntypedDefBehavior::SkipAndInferReturnAny),
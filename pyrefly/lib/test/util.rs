@@ -26,17 +26,21 @@ use ruff_text_size::TextRange;
 use ruff_text_size::TextSize;
 use starlark_map::small_map::SmallMap;
 
+use crate::alt::answers::AnswersSolver;
 use crate::binding::binding::KeyExport;
 use crate::config::base::UntypedDefBehavior;
 use crate::config::config::ConfigFile;
+use crate::config::error::ErrorDisplayConfig;
 use crate::config::finder::ConfigFinder;
 use crate::error::error::print_errors;
+use crate::error::kind::ErrorKind;
 use crate::module::module_name::ModuleName;
 use crate::module::module_path::ModulePath;
 use crate::module::module_path::ModulePathDetails;
 use crate::state::handle::Handle;
 use crate::state::require::Require;
 use crate::state::state::State;
+use crate::state::state::TransactionHandle;
 use crate::state::subscriber::TestSubscriber;
 use crate::sys_info::PythonPlatform;
 use crate::sys_info::PythonVersion;
@@ -96,6 +100,7 @@ pub struct TestEnv {
     modules: SmallMap<ModuleName, (ModulePath, Option<Arc<String>>)>,
     version: PythonVersion,
     untyped_def_behavior: UntypedDefBehavior,
+    enabled_errors: Vec<ErrorKind>,
 }
 
 impl TestEnv {
@@ -117,6 +122,13 @@ impl TestEnv {
         res
     }
 
+    /// Turn on error kinds that are disabled by default, such as opt-in warnings.
+    pub fn new_with_enabled_errors(kinds: &[ErrorKind]) -> Self {
+        let mut res = Self::new();
+        res.enabled_errors = kinds.to_vec();
+        res
+    }
+
     pub fn add_with_path(&mut self, name: &str, path: &str, code: &str) {
         assert!(
             path.ends_with(".py") || path.ends_with(".pyi") || path.ends_with(".rs"),
@@ -178,6 +190,14 @@ impl TestEnv {
         config.python_environment.python_platform = Some(PythonPlatform::linux());
         config.python_environment.site_package_path = Some(Vec::new());
         config.root.untyped_def_behavior = Some(self.untyped_def_behavior);
+        if !self.enabled_errors.is_empty() {
+            config.root.errors = Some(ErrorDisplayConfig::new(
+                self.enabled_errors
+                    .iter()
+                    .map(|kind| (*kind, true))
+                    .collect(),
+            ));
+        }
         for (name, (path, _)) in self.modules.iter() {
             config.custom_module_paths.insert(*name, path.clone());
         }
@@ -472,6 +492,32 @@ pub fn get_class(name: &str, handle: &Handle, state: &State) -> Class {
     }
 }
 
+/// Check `code` as the module `main`, then call `solve` with a solver for it. Useful for testing
+/// `AnswersSolver` helpers that aren't reachable from Python code.
+pub fn solve_in_main<R>(
+    code: &str,
+    solve: impl FnOnce(&AnswersSolver<TransactionHandle>) -> R,
+) -> R {
+    let (handle, state) = mk_state(code);
+    state
+        .transaction()
+        .ad_hoc_solve(&handle, |solver| solve(&solver))
+        .unwrap()
+}
+
+/// The type of the module-level name `name`, from within `solve_in_main`.
+pub fn get_export(solver: &AnswersSolver<TransactionHandle>, name: &str) -> Type {
+    (*solver.get(&KeyExport(Name::new(name)))).clone()
+}
+
+/// The class defined at module level as `name`, from within `solve_in_main`.
+pub fn get_export_class(solver: &AnswersSolver<TransactionHandle>, name: &str) -> Class {
+    match get_export(solver, name) {
+        Type::ClassDef(cls) => cls,
+        _ => unreachable!(),
+    }
+}
+
 // Utility structure to facilitate setting up non-memory filesystem structure under test directories.
 pub enum TestPathKind {
     File,
@@ -5,6 +5,8 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::error::kind::ErrorKind;
+use crate::test::util::TestEnv;
 use crate::testcase;
 
 testcase!(
@@ -283,3 +285,102 @@ def g() -> bool:
         return True
     "#,
 );
+
+testcase!(
+    test_with_contextmanager_reused,
+    TestEnv::new_with_enabled_errors(&[ErrorKind::ReusedContextManager]),
+    r#"
+import contextlib
+from typing import Iterator
+
+@contextlib.contextmanager
+def f() -> Iterator[str]:
+    yield ""
+
+cm = f()
+with cm:
+    pass
+with cm:  # E: generator-based context managers can only be entered once
+    pass
+with f():
+    pass
+with f():
+    pass
+    "#,
+);
+
+testcase!(
+    test_with_contextmanager_reused_in_branches,
+    TestEnv::new_with_enabled_errors(&[ErrorKind::ReusedContextManager]),
+    r#"
+import contextlib
+from typing import Iterator
+
+@contextlib.contextmanager
+def f() -> Iterator[str]:
+    yield ""
+
+def g(c: bool) -> None:
+    cm = f()
+    if c:
+        with cm:
+            pass
+    else:
+        with cm:
+            pass
+
+def h(c: bool) -> None:
+    cm = f()
+    if c:
+        with cm:
+            pass
+    else:
+        with cm:
+            pass
+    with cm:  # E: generator-based context managers can only be entered once
+        pass
+
+def i(c: bool) -> None:
+    cm = f()
+    if c:
+        with cm:
+            pass
+    with cm:
+        pass
+    "#,
+);
+
+testcase!(
+    test_with_contextmanager_reused_disabled_by_default,
+    r#"
+import contextlib
+from typing import Iterator
+
+@contextlib.contextmanager
+def f() -> Iterator[str]:
+    yield ""
+
+cm = f()
+with cm:
+    pass
+with cm:
+    pass
+    "#,
+);
+
+testcase!(
+    test_with_reused_class_context_manager,
+    TestEnv::new_with_enabled_errors(&[ErrorKind::ReusedContextManager]),
+    r#"
+from typing import Self
+class Foo:
+    def __enter__(self) -> Self: ...
+    def __exit__(self, *args: object) -> None: ...
+
+foo = Foo()
+with foo:
+    pass
+with foo:
+    pass
+    "#,
+);
@@ -5,6 +5,8 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use crate::test::util::get_export;
+use crate::test::util::solve_in_main;
 use crate::testcase;
 
 testcase!(
@@ -320,9 +322,43 @@ async def gen() -> AsyncGenerator[int, None]:
 async def test() -> None:
     async for x in gen():
         assert_type(x, int)
-    async for y in [1, 2, 3]:  # E: Type `list[int]` is not an async iterable
+    async for y in [1, 2, 3]:  # E: Type `list[int]` is not an async iterable, it is a sync iterable, use a regular `for` instead
+        assert_type(y, int)
+    async for w in 42:  # E: Type `Literal[42]` is not an async iterable
         pass
     for z in gen():  # E: Type `AsyncGenerator[int, None]` is not iterable
         pass
 "#,
 );
+
+#[test]
+fn test_awaited_type() {
+    let results = solve_in_main(
+        r#"
+from typing import Any, Generator
+async def f() -> int: ...
+c = f()
+class A:
+    def __await__(self) -> Generator[Any, Any, str]: ...
+a: A = A()
+n: int = 0
+"#,
+        |solver| {
+            ["c", "a", "n"].map(|name| {
+                let ty = get_export(solver, name);
+                (
+                    solver.awaited_type(&ty).map(|ty| ty.to_string()),
+                    solver.is_awaitable(&ty),
+                )
+            })
+        },
+    );
+    assert_eq!(
+        results,
+        [
+            (Some("int".to_owned()), true),
+            (Some("str".to_owned()), true),
+            (None, false),
+        ]
+    );
+}
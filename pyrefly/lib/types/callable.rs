@@ -216,6 +216,9 @@ pub enum FunctionKind {
     IsSubclass,
     Dataclass(Box<BoolKeywords>),
     DataclassField,
+    /// `typing.dataclass_transform`, or the result of calling it. The keywords are the
+    /// `*_default` arguments, stored under the dataclass keyword whose default they change.
+    DataclassTransform(Box<BoolKeywords>),
     ClassMethod,
     Overload,
     Override,
@@ -228,6 +231,9 @@ pub enum FunctionKind {
     AbstractMethod,
     /// Instance of a protocol with a `__call__` method. The function has the `__call__` signature.
     CallbackProtocol(Box<ClassType>),
+    /// A `functools.partial` object. The function has the signature left over once the bound
+    /// arguments are applied, and attribute access goes to the `partial` instance.
+    Partial(Box<ClassType>),
 }
 
 /// A map from keywords to boolean values. Useful for storing sets of keyword arguments for various
@@ -286,6 +292,18 @@ impl DataclassKeywords {
     pub const DEFAULT: (Name, bool) = (Name::new_static("default"), false);
     pub const EQ: (Name, bool) = (Name::new_static("eq"), true);
     pub const UNSAFE_HASH: (Name, bool) = (Name::new_static("unsafe_hash"), false);
+
+    /// Maps a `dataclass_transform` parameter like `frozen_default` to the keyword it sets the
+    /// default for.
+    pub fn from_transform_default(name: &Name) -> Option<Name> {
+        match name.as_str() {
+            "eq_default" => Some(Self::EQ.0),
+            "order_default" => Some(Self::ORDER.0),
+            "kw_only_default" => Some(Self::KW_ONLY.0),
+            "frozen_default" => Some(Self::FROZEN.0),
+            _ => None,
+        }
+    }
 }
 
 impl Callable {
@@ -485,6 +503,9 @@ impl FunctionKind {
             ("typing", None, "assert_type") => Self::AssertType,
             ("typing", None, "reveal_type") => Self::RevealType,
             ("typing", None, "final") => Self::Final,
+            ("typing", None, "dataclass_transform") => {
+                Self::DataclassTransform(Box::new(BoolKeywords::new()))
+            }
             ("abc", None, "abstractmethod") => Self::AbstractMethod,
             _ => Self::Def(Box::new(FuncId {
                 module,
@@ -521,6 +542,11 @@ impl FunctionKind {
                 cls: None,
                 func: Name::new_static("field"),
             },
+            Self::DataclassTransform(_) => FuncId {
+                module: ModuleName::typing(),
+                cls: None,
+                func: Name::new_static("dataclass_transform"),
+            },
             Self::Final => FuncId {
                 module: ModuleName::typing(),
                 cls: None,
@@ -551,7 +577,7 @@ impl FunctionKind {
                 cls: None,
                 func: Name::new_static("reveal_type"),
             },
-            Self::CallbackProtocol(cls) => FuncId {
+            Self::CallbackProtocol(cls) | Self::Partial(cls) => FuncId {
                 module: cls.qname().module_name(),
                 cls: Some(cls.name().clone()),
                 func: dunder::CALL,
@@ -128,6 +128,13 @@ impl Class {
             .is_some_and(|prop| prop.is_annotated)
     }
 
+    pub fn is_field_abstract_method(&self, name: &Name) -> bool {
+        self.0
+            .fields
+            .get(name)
+            .is_some_and(|prop| prop.is_abstract_method)
+    }
+
     pub fn field_decl_range(&self, name: &Name) -> Option<TextRange> {
         Some(self.0.fields.get(name)?.range)
     }
@@ -197,12 +204,15 @@ impl Visit<Type> for Class {
 #[derive(Debug, Clone)]
 pub struct ClassFieldProperties {
     is_annotated: bool,
+    /// Is the field a method decorated with `@abstractmethod`?
+    is_abstract_method: bool,
     range: TextRange,
 }
 
 impl PartialEq for ClassFieldProperties {
     fn eq(&self, other: &Self) -> bool {
         self.is_annotated == other.is_annotated
+            && self.is_abstract_method == other.is_abstract_method
     }
 }
 
@@ -215,9 +225,10 @@ impl TypeEq for ClassFieldProperties {}
 pub struct ClassDefIndex(pub u32);
 
 impl ClassFieldProperties {
-    pub fn new(is_annotated: bool, range: TextRange) -> Self {
+    pub fn new(is_annotated: bool, is_abstract_method: bool, range: TextRange) -> Self {
         Self {
             is_annotated,
+            is_abstract_method,
             range,
         }
     }
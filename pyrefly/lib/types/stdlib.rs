@@ -312,6 +312,10 @@ impl Stdlib {
         Self::apply(&self.mapping, vec![key, value])
     }
 
+    pub fn mapping_class_object(&self) -> Class {
+        Self::unwrap(&self.mapping).dupe()
+    }
+
     pub fn set(&self, x: Type) -> ClassType {
         Self::apply(&self.set, vec![x])
     }